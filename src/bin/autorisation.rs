@@ -2,68 +2,752 @@
 #![forbid(unsafe_code)]
 
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 
 use autorisation::domain::form::AutorisationForm;
-use autorisation::infra::fs::resolve_out_path;
-use autorisation::render::{markdown, pdf};
+use autorisation::domain::parse::{InputFormat, ParseError};
+use autorisation::domain::school::School;
+use autorisation::domain::source;
+use autorisation::domain::validation::{
+    validate_input, validate_source, Diagnostic, ValidationReport,
+};
+use autorisation::i18n::Locale;
+use autorisation::infra::config::{create_profile, set_value, use_profile, Config, CONFIG_FILE};
+use autorisation::infra::fs::{resolve_out_path, write_atomic};
+use autorisation::render::{
+    render_pdf_imposed, Bilingual, BilingualLayout, DocumentLayout, Imposition, Margins,
+    Orientation, PageConfig, PaperSize, PdfBackend, PdfRenderer, Registry, RenderContext,
+    RenderOptions, Renderer, Template, Theme,
+};
+
+/// Chemin `-` de `--input`/`--out` : entrée ou sortie standard.
+const STDIO: &str = "-";
+
+/// Fichier de sortie sans `--out`.
+const DEFAULT_OUT: &str = "autorisation_sortie.pdf";
 
 #[derive(Parser)]
 #[command(
     name = "autorisation",
-    about = "Génère une autorisation de sortie (PDF/MD) - offline"
+    about = "Génère une autorisation de sortie (PDF/MD...) - offline",
+    args_conflicts_with_subcommands = true
 )]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Fichier d'entrée (YAML/JSON) ; `-` lit l'entrée standard (YAML par défaut). Répétable
+    /// avec `--imposition two-up` : un PDF unique, deux formulaires par feuille
     #[arg(long)]
-    input: Option<String>,
+    input: Vec<String>,
 
     #[arg(long, conflicts_with = "input")]
     interactive: bool,
 
-    #[arg(long, default_value = "autorisation_sortie.pdf")]
-    out: String,
+    /// Format du fichier d'entrée (yaml, json) ; déduit de l'extension par défaut
+    #[arg(long, requires = "input")]
+    input_format: Option<InputFormat>,
+
+    /// Fichier de sortie ; son extension est remplacée par celle de chaque format. `-` écrit
+    /// l'unique format demandé sur la sortie standard. Par défaut `autorisation_sortie.pdf`,
+    /// dans `out_dir` de la configuration (ou AUT_OUT_DIR) s'il est défini
+    #[arg(long)]
+    out: Option<String>,
+
+    /// Formats de sortie séparés par des virgules (pdf, md, typ...)
+    #[arg(long, value_delimiter = ',', default_value = "pdf")]
+    format: Vec<String>,
 
+    /// Écrit aussi le Markdown à ce chemin (`-` : sortie standard)
     #[arg(long)]
     md: Option<String>,
 
+    /// Gabarits Jinja des sorties Markdown et texte : `md=ecole.md,txt=ecole.txt`, ou un chemin
+    /// seul si un seul de ces formats est demandé ; par défaut ceux de la crate
+    #[arg(long, value_delimiter = ',')]
+    template: Vec<String>,
+
+    /// Nom de l'école ; remplace celui du profil (`--school` ou configuration)
     #[arg(long)]
     school_name: Option<String>,
+
+    /// Profil de l'école (.toml/.yaml) : nom, logo, adresse, téléphone, courriel, académie ;
+    /// à défaut, la section [school] de la configuration
+    #[arg(long)]
+    school: Option<PathBuf>,
+
+    /// Langue du document (fr, en, es, de, ar) ; prioritaire sur le champ `langue`
+    #[arg(long)]
+    lang: Option<Locale>,
+
+    /// Seconde langue affichée à côté de la langue principale (document bilingue)
+    #[arg(long)]
+    bilingual: Option<Locale>,
+
+    /// Mise en page bilingue : stacked (lignes) ou columns (colonnes)
+    #[arg(long, default_value = "stacked", requires = "bilingual")]
+    bilingual_layout: BilingualLayout,
+
+    /// Mise en page : full (un seul bloc) ou coupon (information + coupon-réponse à découper)
+    #[arg(long, default_value = "full")]
+    layout: DocumentLayout,
+
+    /// Moteur PDF : auto (Typst, puis genpdf, puis polices standard), typst, genpdf ou builtin
+    #[arg(long, default_value = "auto")]
+    backend: PdfBackend,
+
+    /// Placement sur la feuille : none, ou two-up (deux A5 sur une A4 paysage, traits de coupe)
+    #[arg(long, default_value = "none")]
+    imposition: Imposition,
+
+    /// Format du papier : a4, a5, letter, legal ou <largeur>x<hauteur>mm (sinon AUT_PAGE_SIZE,
+    /// puis le fichier de configuration, puis A4)
+    #[arg(long)]
+    paper: Option<PaperSize>,
+
+    /// Orientation : portrait ou landscape (sinon AUT_ORIENTATION, puis la configuration)
+    #[arg(long)]
+    orientation: Option<Orientation>,
+
+    /// Marges en mm, 1 à 4 valeurs dans l'ordre CSS : 15, 18,20 ou 18,20,18,25 (sinon
+    /// AUT_MARGINS, puis la configuration)
+    #[arg(long)]
+    margins: Option<Margins>,
+
+    /// Thème : compact, standard, large-print, ou fichier .toml/.yaml (tailles, couleurs, police)
+    #[arg(long)]
+    theme: Option<String>,
+
+    /// Fichier de configuration TOML du projet (par défaut `autorisation.toml` s'il existe),
+    /// complété par celui de l'utilisateur (voir `autorisation config show`)
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Profil nommé de la configuration (école, logo, thème, classes) ; sinon AUT_PROFILE,
+    /// puis le profil actif (`autorisation profile use`)
+    #[arg(long)]
+    profile: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Vérifie des fichiers sans rien générer.
+    ///
+    /// Codes de sortie : 0 tout est valide, 1 erreurs de validation, 2 format de fichier
+    /// inconnu, 3 erreurs de syntaxe, 4 erreurs d'entrée/sortie (le plus grave l'emporte).
+    Validate {
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Format des diagnostics
+        #[arg(long, value_enum, default_value = "human")]
+        format: DiagnosticFormat,
+
+        /// Format des fichiers (yaml, json) ; déduit de l'extension par défaut
+        #[arg(long)]
+        input_format: Option<InputFormat>,
+    },
+    /// Consulte ou modifie les fichiers de configuration.
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
+    },
+    /// Gère les profils nommés (une école par profil).
+    Profile {
+        #[command(subcommand)]
+        action: ProfileCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Affiche la configuration effective (environnement, projet, utilisateur) et les
+    /// fichiers lus.
+    Show {
+        /// Fichier de configuration du projet
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Profil à appliquer ; sinon AUT_PROFILE, puis le profil actif
+        #[arg(long)]
+        profile: Option<String>,
+    },
+    /// Enregistre un réglage (`school.name`, `page.paper`, `defaults.lieu`, `lang`...) dans
+    /// la configuration de l'utilisateur.
+    Set {
+        key: String,
+        value: String,
+
+        /// Écrit dans `autorisation.toml` du répertoire courant plutôt que chez l'utilisateur
+        #[arg(long)]
+        project: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommand {
+    /// Liste les profils ; `*` marque le profil actif.
+    List {
+        /// Fichier de configuration du projet
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Active un profil par défaut (clé `profile` de la configuration de l'utilisateur).
+    Use {
+        name: String,
+
+        /// Fichier de configuration du projet
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Crée un profil.
+    Create {
+        name: String,
+
+        /// Nom de l'école
+        #[arg(long, conflicts_with = "school")]
+        school_name: Option<String>,
+
+        /// Profil de l'école (.toml/.yaml), recopié dans le profil
+        #[arg(long)]
+        school: Option<PathBuf>,
+
+        /// Thème du profil
+        #[arg(long)]
+        theme: Option<String>,
+
+        /// Classes de l'école, séparées par des virgules
+        #[arg(long, value_delimiter = ',')]
+        classes: Vec<String>,
+
+        /// Écrit dans `autorisation.toml` du répertoire courant plutôt que chez l'utilisateur
+        #[arg(long)]
+        project: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiagnosticFormat {
+    Human,
+    Json,
+}
+
+const EXIT_INVALID: i32 = 1;
+const EXIT_FORMAT: i32 = 2;
+const EXIT_PARSE: i32 = 3;
+const EXIT_IO: i32 = 4;
+
+/// Résultat de la vérification d'un fichier.
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum FileCheck {
+    Ok { diagnostics: Vec<Diagnostic> },
+    Invalid { diagnostics: Vec<Diagnostic> },
+    UnknownFormat { message: String },
+    ParseError { error: ParseError },
+    IoError { message: String },
+}
+
+impl FileCheck {
+    fn exit_code(&self) -> i32 {
+        match self {
+            FileCheck::Ok { .. } => 0,
+            FileCheck::Invalid { .. } => EXIT_INVALID,
+            FileCheck::UnknownFormat { .. } => EXIT_FORMAT,
+            FileCheck::ParseError { .. } => EXIT_PARSE,
+            FileCheck::IoError { .. } => EXIT_IO,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FileReport {
+    file: PathBuf,
+    #[serde(flatten)]
+    check: FileCheck,
+}
+
+fn check_file(path: &PathBuf, format: Option<InputFormat>) -> FileCheck {
+    let format = match InputFormat::resolve(path, format) {
+        Ok(f) => f,
+        Err(e) => {
+            return FileCheck::UnknownFormat {
+                message: e.to_string(),
+            }
+        }
+    };
+    let content = match std::fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            return FileCheck::IoError {
+                message: e.to_string(),
+            }
+        }
+    };
+    match validate_source(&content, format) {
+        Ok((_, report)) => {
+            let diagnostics = report.diagnostics(&content);
+            if report.has_errors() {
+                FileCheck::Invalid { diagnostics }
+            } else {
+                FileCheck::Ok { diagnostics }
+            }
+        }
+        Err(error) => FileCheck::ParseError { error },
+    }
+}
+
+fn position(line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(l), Some(c)) => format!(":{l}:{c}"),
+        _ => String::new(),
+    }
+}
+
+/// `fichier:ligne:colonne: violation`, suivi de l'extrait de source quand il est connu.
+fn diagnostic_line(file: &str, content: &str, d: &Diagnostic) -> String {
+    let mut out = format!("{file}{}: {}", position(d.line, d.column), d.violation);
+    if let (Some(l), Some(c)) = (d.line, d.column) {
+        out = format!("{out}\n{}", source::snippet(content, l, c));
+    }
+    out
+}
+
+fn print_human(r: &FileReport, content: Option<&str>) {
+    let file = r.file.display().to_string();
+    match &r.check {
+        FileCheck::Ok { diagnostics } | FileCheck::Invalid { diagnostics } => {
+            for d in diagnostics {
+                println!("{}", diagnostic_line(&file, content.unwrap_or(""), d));
+            }
+        }
+        FileCheck::ParseError { error } => {
+            println!(
+                "{file}{}: error[parse_error] {}",
+                position(error.line, error.column),
+                error.message
+            );
+            if let Some(snippet) = &error.snippet {
+                println!("{snippet}");
+            }
+        }
+        FileCheck::UnknownFormat { message } => println!("{file}: error[unknown_format] {message}"),
+        FileCheck::IoError { message } => println!("{file}: error[io_error] {message}"),
+    }
+}
+
+/// `autorisation validate` : renvoie le code de sortie.
+fn run_validate(
+    files: &[PathBuf],
+    format: DiagnosticFormat,
+    input_format: Option<InputFormat>,
+) -> Result<i32> {
+    let reports: Vec<FileReport> = files
+        .iter()
+        .map(|f| FileReport {
+            file: f.clone(),
+            check: check_file(f, input_format),
+        })
+        .collect();
+    match format {
+        DiagnosticFormat::Human => {
+            for r in &reports {
+                let content = std::fs::read_to_string(&r.file).ok();
+                print_human(r, content.as_deref());
+            }
+            let failed = reports.iter().filter(|r| r.check.exit_code() != 0).count();
+            eprintln!(
+                "{} fichier(s) vérifié(s), {failed} en erreur",
+                reports.len()
+            );
+        }
+        DiagnosticFormat::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+    }
+    Ok(reports
+        .iter()
+        .map(|r| r.check.exit_code())
+        .max()
+        .unwrap_or(0))
+}
+
+/// Lit et valide `--input` ; `config` fournit les valeurs des champs absents (lieu,
+/// signataire, langue). Les violations sont affichées avec leur position et un extrait de la
+/// source.
+fn load_input(
+    path: &str,
+    format: Option<InputFormat>,
+    config: &Config,
+) -> Result<(AutorisationForm, ValidationReport)> {
+    let (name, format, content) = if path == STDIO {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .context("read standard input")?;
+        ("<stdin>", format.unwrap_or(InputFormat::Yaml), content)
+    } else {
+        let format = InputFormat::resolve(path, format)?;
+        let content = std::fs::read_to_string(path).context("read input file")?;
+        (path, format, content)
+    };
+    let (form, report) = validate_input(&content, format, &config.defaults, &config.classes)?;
+    for d in report.diagnostics(&content) {
+        eprintln!("{}", diagnostic_line(name, &content, &d));
+    }
+    match form {
+        Some(mut form) if !report.has_errors() => {
+            form.langue = form.langue.or(config.lang);
+            Ok((form, report))
+        }
+        _ => Err(anyhow::anyhow!(
+            "{} erreur(s) de validation",
+            report.errors().count()
+        )),
+    }
+}
+
+/// Plusieurs `--input` : un seul PDF imposé, deux formulaires par feuille.
+fn run_batch(cli: &Cli, opts: &RenderOptions, out: &str, config: &Config) -> Result<()> {
+    if opts.imposition != Imposition::TwoUp {
+        anyhow::bail!("plusieurs --input demandent --imposition two-up");
+    }
+    if cli.format != ["pdf"] || cli.md.is_some() {
+        anyhow::bail!("plusieurs --input ne produisent qu'un PDF (--format pdf, sans --md)");
+    }
+    let mut forms = Vec::new();
+    for path in &cli.input {
+        let (form, _) = load_input(path, cli.input_format, config)
+            .with_context(|| format!("failed to load input file '{path}'"))?;
+        check_bilingual(opts, &form).with_context(|| format!("'{path}'"))?;
+        forms.push(form);
+    }
+    let bytes = render_pdf_imposed(cli.backend, &forms, opts).context("pdf generation failed")?;
+    if out == STDIO {
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(&bytes)?;
+        stdout.flush()?;
+        info!("Wrote pdf to stdout");
+    } else {
+        let path = resolve_out_path(out)?.with_extension("pdf");
+        write_atomic(&path, &bytes).with_context(|| format!("écriture de {}", path.display()))?;
+        info!("Wrote pdf {} ({} formulaires)", path.display(), forms.len());
+    }
+    Ok(())
+}
+
+/// `--template` par id de renderer ; un chemin seul va au seul format `md` ou `txt` demandé.
+fn templates(cli: &Cli) -> Result<BTreeMap<String, Template>> {
+    const IDS: [&str; 2] = ["md", "txt"];
+    let requested: Vec<&str> = IDS
+        .into_iter()
+        .filter(|id| cli.format.iter().any(|f| f == id) || (*id == "md" && cli.md.is_some()))
+        .collect();
+    let mut templates = BTreeMap::new();
+    for spec in &cli.template {
+        let (id, path) = match (spec.split_once('='), requested.as_slice()) {
+            (Some((id, path)), _) if IDS.contains(&id) => (id, path),
+            (_, [id]) => (*id, spec.as_str()),
+            (_, []) => anyhow::bail!("--template {spec} : aucun format md ou txt demandé"),
+            _ => anyhow::bail!("--template {spec} : précisez md={spec} ou txt={spec}"),
+        };
+        let template = Template::load(Path::new(path))?;
+        if templates.insert(id.to_string(), template).is_some() {
+            anyhow::bail!("--template : plusieurs gabarits pour {id}");
+        }
+    }
+    Ok(templates)
+}
+
+/// `--bilingual` ajoute une seconde langue au texte français, qui fait foi : le document doit
+/// être en français et la seconde langue en différer.
+fn check_bilingual(opts: &RenderOptions, form: &AutorisationForm) -> Result<()> {
+    let Some(bi) = opts.bilingual else {
+        return Ok(());
+    };
+    let primary = opts.locale(form);
+    if primary != Locale::Fr {
+        anyhow::bail!(
+            "--bilingual demande un document en français (langue {} par --lang ou `langue`)",
+            primary.code()
+        );
+    }
+    if bi.secondary == Locale::Fr {
+        anyhow::bail!("--bilingual fr : c'est déjà la langue du document");
+    }
+    Ok(())
+}
+
+/// Fichier modifié par `config set` et `profile create` : le projet ou l'utilisateur.
+fn target_path(project: bool) -> Result<PathBuf> {
+    if project {
+        Ok(PathBuf::from(CONFIG_FILE))
+    } else {
+        Config::user_path().context("répertoire de configuration de l'utilisateur introuvable")
+    }
+}
+
+/// `config show` / `config set`.
+fn run_config(action: &ConfigCommand) -> Result<()> {
+    match action {
+        ConfigCommand::Show { config, profile } => {
+            let (effective, files) = Config::effective(config.as_deref(), profile.as_deref())?;
+            let mut stdout = std::io::stdout().lock();
+            if files.is_empty() {
+                writeln!(stdout, "# aucun fichier de configuration")?;
+            }
+            for path in &files {
+                writeln!(stdout, "# {}", path.display())?;
+            }
+            if let Some(user) = Config::user_path().filter(|p| !files.contains(p)) {
+                writeln!(stdout, "# (utilisateur : {}, absent)", user.display())?;
+            }
+            write!(stdout, "{}", effective.to_toml()?)?;
+        }
+        ConfigCommand::Set {
+            key,
+            value,
+            project,
+        } => {
+            let path = target_path(*project)?;
+            set_value(&path, key, value)?;
+            eprintln!("{key} = {value} ({})", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// `profile list` / `profile use` / `profile create`.
+fn run_profile(action: &ProfileCommand) -> Result<()> {
+    match action {
+        ProfileCommand::List { config } => {
+            let (files, _) = Config::discover(config.as_deref())?;
+            let active = std::env::var("AUT_PROFILE")
+                .ok()
+                .filter(|p| !p.is_empty())
+                .or(files.profile);
+            if files.profiles.is_empty() {
+                eprintln!("aucun profil (autorisation profile create <nom>)");
+            }
+            let mut stdout = std::io::stdout().lock();
+            for (name, profile) in &files.profiles {
+                let mark = if active.as_deref() == Some(name) {
+                    '*'
+                } else {
+                    ' '
+                };
+                match profile.school.as_ref().map(|s| s.name.as_str()) {
+                    Some(school) => writeln!(stdout, "{mark} {name}\t{school}")?,
+                    None => writeln!(stdout, "{mark} {name}")?,
+                }
+            }
+        }
+        ProfileCommand::Use { name, config } => {
+            let (files, _) = Config::discover(config.as_deref())?;
+            let path = target_path(false)?;
+            use_profile(&path, name, &files)?;
+            eprintln!("profil actif : {name} ({})", path.display());
+        }
+        ProfileCommand::Create {
+            name,
+            school_name,
+            school,
+            theme,
+            classes,
+            project,
+        } => {
+            let school = match (school, school_name) {
+                (Some(path), _) => {
+                    // logo en chemin absolu : le profil ne dépend plus du fichier d'origine
+                    let mut school = School::load(path)?;
+                    if let Some(logo) = school.logo.take() {
+                        school.logo = Some(
+                            std::fs::canonicalize(&logo)
+                                .with_context(|| format!("logo {}", logo.display()))?,
+                        );
+                    }
+                    Some(school)
+                }
+                (None, Some(name)) => Some(School::named(name.clone())),
+                (None, None) => None,
+            };
+            // fichier de thème en chemin absolu, comme le logo
+            let theme = match theme {
+                Some(spec) => {
+                    Theme::resolve(spec)?;
+                    Some(match Theme::builtin(spec) {
+                        Some(_) => spec.clone(),
+                        None => std::fs::canonicalize(spec)
+                            .with_context(|| format!("thème {spec}"))?
+                            .to_string_lossy()
+                            .into_owned(),
+                    })
+                }
+                None => None,
+            };
+            let profile = Config {
+                theme,
+                classes: classes.clone(),
+                school,
+                ..Config::default()
+            };
+            let path = target_path(*project)?;
+            create_profile(&path, name, &profile)?;
+            eprintln!("profil {name} créé ({})", path.display());
+        }
+    }
+    Ok(())
 }
 
 fn main() -> Result<()> {
-    // Logging
+    // Logging (sur stderr : la sortie standard peut porter le document, cf. `--out -`)
     tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
         .init();
 
     let cli = Cli::parse();
     info!("Start autorisation CLI");
 
-    let form = if cli.interactive {
-        AutorisationForm::from_interactive()?
-    } else if let Some(path) = cli.input {
-        AutorisationForm::from_file(&path)
+    if let Some(Command::Validate {
+        files,
+        format,
+        input_format,
+    }) = &cli.command
+    {
+        let code = run_validate(files, *format, *input_format)?;
+        std::process::exit(code);
+    }
+    match &cli.command {
+        Some(Command::Config { action }) => return run_config(action),
+        Some(Command::Profile { action }) => return run_profile(action),
+        _ => {}
+    }
+
+    // ligne de commande > environnement > profil > projet > utilisateur > défauts
+    let (config, files) = Config::effective(cli.config.as_deref(), cli.profile.as_deref())?;
+    for path in &files {
+        info!("Configuration {}", path.display());
+    }
+    if let Some(profile) = &config.profile {
+        info!("Profil {profile}");
+    }
+    let page = PageConfig {
+        paper: cli.paper,
+        orientation: cli.orientation,
+        margins: cli.margins,
+    }
+    .or(config.page)
+    .resolve()?;
+    let theme = match cli.theme.as_deref().or(config.theme.as_deref()) {
+        Some(spec) => Theme::resolve(spec)?,
+        None => Theme::default(),
+    };
+    let mut school = match &cli.school {
+        Some(path) => Some(School::load(path)?),
+        None => config.school.clone(),
+    };
+    if let Some(name) = &cli.school_name {
+        school.get_or_insert_with(School::default).name = name.clone();
+    }
+
+    let opts = RenderOptions {
+        school,
+        lang: cli.lang,
+        bilingual: cli.bilingual.map(|secondary| Bilingual {
+            secondary,
+            layout: cli.bilingual_layout,
+        }),
+        layout: cli.layout,
+        imposition: cli.imposition,
+        page,
+        theme,
+        profile: config.profile.clone(),
+        templates: templates(&cli)?,
+    };
+
+    let out = match (&cli.out, &config.out_dir) {
+        (Some(out), _) => out.clone(),
+        (None, Some(dir)) => {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("création de {}", dir.display()))?;
+            dir.join(DEFAULT_OUT).to_string_lossy().into_owned()
+        }
+        (None, None) => DEFAULT_OUT.to_string(),
+    };
+
+    if cli.input.len() > 1 {
+        return run_batch(&cli, &opts, &out, &config);
+    }
+
+    let (form, report) = if cli.interactive {
+        let mut form = AutorisationForm::from_interactive_with(&config.classes, &config.defaults)?;
+        form.langue = form.langue.or(config.lang);
+        let mut report = form.report();
+        report.extend(form.check_classes(&config.classes));
+        (form, report)
+    } else if let Some(path) = cli.input.first() {
+        load_input(path, cli.input_format, &config)
             .with_context(|| format!("failed to load input file '{path}'"))?
     } else {
         anyhow::bail!("Either --input <file> or --interactive must be provided");
     };
 
-    form.validate().context("validation failed")?;
+    if cli.interactive {
+        for w in report.warnings() {
+            eprintln!("{w}");
+        }
+    }
+    report.into_result().context("validation failed")?;
+    check_bilingual(&opts, &form)?;
 
-    if let Some(md_out) = cli.md.as_ref() {
-        let md = markdown::render_markdown(&form, cli.school_name.as_deref())?;
-        autorisation::infra::fs::write_atomic(md_out, md.as_bytes())
-            .context("failed to write markdown output")?;
-        info!("Wrote markdown {}", md_out);
+    let mut registry = Registry::with_defaults();
+    registry.register(PdfRenderer {
+        backend: cli.backend,
+    });
+    // `None` : sortie standard
+    let mut targets: Vec<(&dyn Renderer, Option<PathBuf>)> = Vec::new();
+    if out == STDIO {
+        if cli.format.len() != 1 {
+            anyhow::bail!("--out - n'accepte qu'un seul format (--format)");
+        }
+        targets.push((registry.require(&cli.format[0])?, None));
+    } else {
+        let out_path = resolve_out_path(&out)?;
+        for id in &cli.format {
+            let renderer = registry.require(id)?;
+            targets.push((
+                renderer,
+                Some(out_path.with_extension(renderer.extension())),
+            ));
+        }
+    }
+    if let Some(md_out) = &cli.md {
+        let path = (md_out != STDIO).then(|| PathBuf::from(md_out));
+        targets.push((registry.require("md")?, path));
+    }
+    if targets.iter().filter(|(_, p)| p.is_none()).count() > 1 {
+        anyhow::bail!("un seul document peut être écrit sur la sortie standard");
     }
 
-    let out_path = resolve_out_path(&cli.out)?;
-    if let Err(e) = pdf::render_pdf(&form, cli.school_name.as_deref(), &out_path) {
-        error!("PDF generation failed: {:?}", e);
-        return Err(e).context("PDF generation failed");
+    let ctx = RenderContext::new(&form, &opts);
+    for (renderer, path) in targets {
+        let res = match &path {
+            Some(path) => renderer.render(&ctx, path),
+            None => renderer.render_to_writer(&ctx, &mut std::io::stdout().lock()),
+        };
+        if let Err(e) = res {
+            error!("{} generation failed: {:?}", renderer.id(), e);
+            return Err(e).with_context(|| format!("{} generation failed", renderer.id()));
+        }
+        match path {
+            Some(path) => info!("Wrote {} {}", renderer.id(), path.display()),
+            None => info!("Wrote {} to stdout", renderer.id()),
+        }
     }
-    info!("Wrote PDF {}", out_path.display());
     Ok(())
 }
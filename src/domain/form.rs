@@ -1,193 +1,602 @@
+use crate::domain::parse::{deserialize, InputFormat};
+use crate::domain::types::{date_jj_mm_aaaa, heure_opt, Name, PhoneNumber};
+use crate::domain::validation::ValidationReport;
+use crate::i18n::Locale;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::sync::OnceLock;
 
-use time::Date;
+pub use crate::domain::types::{parse_date, parse_heure};
+use time::{Date, OffsetDateTime, Time};
+
+/// Lien de parenté d'un responsable légal avec l'enfant.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Lien {
+    #[serde(alias = "mère")]
+    Mere,
+    #[serde(alias = "père")]
+    Pere,
+    #[serde(alias = "tutrice")]
+    Tuteur,
+    Autre,
+}
+
+impl Lien {
+    pub const ALL: &'static [Lien] = &[Lien::Mere, Lien::Pere, Lien::Tuteur, Lien::Autre];
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct Responsable {
-    pub nom: String,
-    pub telephone: Option<String>,
+    pub nom: Name,
+    pub lien: Option<Lien>,
+    pub telephone: Option<PhoneNumber>,
+    pub email: Option<String>,
+    pub adresse: Option<String>,
+    /// Doit signer le document (garde partagée : les deux parents signent). Vrai par défaut.
+    #[serde(default = "default_true")]
+    pub signataire: bool,
+}
+
+fn default_true() -> bool {
+    true
 }
 
+/// Personne à prévenir en cas d'urgence (ne signe pas).
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
+pub struct ContactUrgence {
+    pub nom: Name,
+    /// Lien libre ("grand-mère", "voisin"...).
+    pub lien: Option<String>,
+    pub telephone: PhoneNumber,
+}
+
+/// Valeurs par défaut de la configuration, reportées dans les champs absents du fichier
+/// d'entrée avant validation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FormDefaults {
+    pub lieu: Option<String>,
+    /// Responsable ajouté (signataire) si le fichier n'en déclare aucun.
+    pub signataire: Option<Responsable>,
+}
+
+impl FormDefaults {
+    /// Complète les valeurs absentes avec celles de `fallback`.
+    pub fn or(self, fallback: FormDefaults) -> FormDefaults {
+        FormDefaults {
+            lieu: self.lieu.or(fallback.lieu),
+            signataire: self.signataire.or(fallback.signataire),
+        }
+    }
+
+    /// Reporte les défauts dans le document brut `value` (objet YAML/JSON), sans toucher
+    /// aux champs présents.
+    pub fn apply(&self, value: &mut serde_json::Value) {
+        let Some(doc) = value.as_object_mut() else {
+            return;
+        };
+        let absent = |doc: &serde_json::Map<String, serde_json::Value>, key: &str| {
+            doc.get(key).map_or(true, |v| {
+                v.is_null() || v.as_array().is_some_and(|a| a.is_empty())
+            })
+        };
+        if let Some(lieu) = self.lieu.as_ref().filter(|_| absent(doc, "lieu")) {
+            doc.insert("lieu".into(), lieu.clone().into());
+        }
+        // l'ancienne clé `responsable` compte aussi : les deux sont exclusives
+        if let Some(resp) = self
+            .signataire
+            .as_ref()
+            .filter(|_| absent(doc, "responsables") && absent(doc, "responsable"))
+        {
+            if let Ok(resp) = serde_json::to_value(resp) {
+                doc.insert("responsables".into(), vec![resp].into());
+            }
+        }
+    }
+}
+
+/// Plage horaire (HH:MM sur le fil) ; `debut` précède toujours strictement `fin`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "PlageHoraireRepr")]
 pub struct PlageHoraire {
-    pub debut: Option<String>, // HH:MM
-    pub fin: Option<String>,   // HH:MM
+    #[serde(with = "heure_opt", skip_serializing_if = "Option::is_none")]
+    debut: Option<Time>,
+    #[serde(with = "heure_opt", skip_serializing_if = "Option::is_none")]
+    fin: Option<Time>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl PlageHoraire {
+    /// Build a time window, checking that `debut` comes strictly before `fin`.
+    pub fn new(debut: Option<Time>, fin: Option<Time>) -> Result<Self> {
+        if let (Some(d), Some(f)) = (debut, fin) {
+            if d >= f {
+                anyhow::bail!("l'heure de début doit précéder l'heure de fin");
+            }
+        }
+        Ok(PlageHoraire { debut, fin })
+    }
+
+    pub fn debut(&self) -> Option<Time> {
+        self.debut
+    }
+
+    pub fn fin(&self) -> Option<Time> {
+        self.fin
+    }
+}
+
+#[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
+struct PlageHoraireRepr {
+    #[serde(default, with = "heure_opt")]
+    debut: Option<Time>,
+    #[serde(default, with = "heure_opt")]
+    fin: Option<Time>,
+}
+
+impl TryFrom<PlageHoraireRepr> for PlageHoraire {
+    type Error = anyhow::Error;
+
+    fn try_from(r: PlageHoraireRepr) -> Result<Self> {
+        PlageHoraire::new(r.debut, r.fin)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, try_from = "AutorisationFormRepr")]
 pub struct AutorisationForm {
-    pub enfant: Enfant,
-    pub date: String, // JJ/MM/AAAA
-    pub lieu: String,
-    pub classe: Option<String>,
-    pub responsable: Option<Responsable>,
+    /// Un ou plusieurs enfants (fratrie) couverts par la même autorisation.
+    pub enfants: Vec<Enfant>,
+    #[serde(with = "date_jj_mm_aaaa")]
+    pub date: Date, // JJ/MM/AAAA
+    pub lieu: Name,
+    /// Responsables légaux ; chaque signataire a son propre bloc de signature.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub responsables: Vec<Responsable>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contacts_urgence: Vec<ContactUrgence>,
     pub plage_horaire: Option<PlageHoraire>,
     pub motif: Option<String>,
+    /// Langue du document (`fr`, `en`, `es`, `de`, `ar`) ; `--lang` a priorité.
+    pub langue: Option<Locale>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct Enfant {
-    pub nom: String,
-    pub prenom: Option<String>,
+    pub nom: Name,
+    pub prenom: Option<Name>,
+    pub classe: Option<String>,
 }
 
-impl AutorisationForm {
-    pub fn from_file(path: &str) -> Result<Self> {
-        let content = fs::read_to_string(path).context("read input file")?;
-        if path.ends_with(".json") {
-            let v = serde_json::from_str(&content).context("parse json")?;
-            Ok(v)
-        } else {
-            // try yaml then json
-            if let Ok(v) = serde_yaml::from_str(&content) {
-                Ok(v)
-            } else {
-                let v: Self = serde_json::from_str(&content).context("parse json fallback")?;
-                Ok(v)
+impl Enfant {
+    /// "Nom Prénom" (sans espace final si le prénom est absent).
+    pub fn nom_complet(&self) -> String {
+        match &self.prenom {
+            Some(p) => format!("{} {p}", self.nom),
+            None => self.nom.to_string(),
+        }
+    }
+}
+
+/// Format d'entrée : accepte `enfants: [...]` ou l'ancien `enfant:` unique avec `classe` au
+/// niveau du formulaire, et `responsables: [...]` ou l'ancien `responsable:` unique.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AutorisationFormRepr {
+    enfant: Option<Enfant>,
+    enfants: Option<Vec<Enfant>>,
+    #[serde(with = "date_jj_mm_aaaa")]
+    date: Date,
+    lieu: Name,
+    classe: Option<String>,
+    responsable: Option<Responsable>,
+    responsables: Option<Vec<Responsable>>,
+    #[serde(default)]
+    contacts_urgence: Vec<ContactUrgence>,
+    plage_horaire: Option<PlageHoraire>,
+    motif: Option<String>,
+    langue: Option<Locale>,
+}
+
+impl TryFrom<AutorisationFormRepr> for AutorisationForm {
+    type Error = String;
+
+    fn try_from(r: AutorisationFormRepr) -> std::result::Result<Self, Self::Error> {
+        let mut enfants = match (r.enfant, r.enfants) {
+            (Some(_), Some(_)) => return Err("`enfant` et `enfants` sont exclusifs".into()),
+            (Some(e), None) => vec![e],
+            (None, Some(v)) => v,
+            (None, None) => return Err("missing field `enfants`".into()),
+        };
+        // ancienne `classe` globale : s'applique aux enfants qui n'ont pas la leur
+        if let Some(classe) = r.classe {
+            for e in enfants.iter_mut().filter(|e| e.classe.is_none()) {
+                e.classe = Some(classe.clone());
             }
         }
+        let responsables = match (r.responsable, r.responsables) {
+            (Some(_), Some(_)) => {
+                return Err("`responsable` et `responsables` sont exclusifs".into())
+            }
+            (Some(resp), None) => vec![resp],
+            (None, v) => v.unwrap_or_default(),
+        };
+        Ok(AutorisationForm {
+            enfants,
+            date: r.date,
+            lieu: r.lieu,
+            responsables,
+            contacts_urgence: r.contacts_urgence,
+            plage_horaire: r.plage_horaire,
+            motif: r.motif,
+            langue: r.langue,
+        })
+    }
+}
+
+impl AutorisationForm {
+    /// Lit `path` au format `format`, ou déduit de l'extension (`.json`, `.yml`/`.yaml`).
+    pub fn from_file(path: &str, format: Option<InputFormat>) -> Result<Self> {
+        let format = InputFormat::resolve(path, format)?;
+        let content = fs::read_to_string(path).context("read input file")?;
+        Ok(deserialize(&content, format)?)
     }
 
     pub fn from_interactive() -> Result<Self> {
+        Self::from_interactive_with(&[], &FormDefaults::default())
+    }
+
+    /// Saisie interactive ; la classe se choisit dans `classes` (liste du profil) si elle
+    /// n'est pas vide, le lieu et le premier responsable sont pré-remplis avec `defaults`.
+    pub fn from_interactive_with(classes: &[String], defaults: &FormDefaults) -> Result<Self> {
         // use dialoguer to prompt ok
-        use dialoguer::Input;
-        let enfant_nom: String = Input::new()
-            .with_prompt("Nom de l'enfant (required)")
-            .validate_with(|input: &String| {
-                if input.trim().is_empty() || input.len() > 80 {
-                    Err("nom vide ou trop long (>80)")
-                } else {
-                    Ok(())
-                }
-            })
-            .interact_text()?;
-        let enfant_prenom: String = Input::new()
-            .with_prompt("Prénom de l'enfant (optionnel)")
-            .allow_empty(true)
-            .interact_text()?;
+        use dialoguer::{Confirm, Input};
+        let mut enfants = Vec::new();
+        loop {
+            let n = enfants.len() + 1;
+            let nom: String = Input::new()
+                .with_prompt(format!("Nom de l'enfant n°{n} (required)"))
+                .validate_with(|s: &String| check_name(s))
+                .interact_text()?;
+            let prenom: String = Input::new()
+                .with_prompt("Prénom de l'enfant (optionnel)")
+                .allow_empty(true)
+                .validate_with(|s: &String| check_opt_name(s))
+                .interact_text()?;
+            let classe = prompt_classe(classes)?;
+            enfants.push(Enfant {
+                nom: Name::new(nom)?,
+                prenom: opt_name(prenom)?,
+                classe,
+            });
+            if !Confirm::new()
+                .with_prompt("Ajouter un autre enfant (fratrie) ?")
+                .default(false)
+                .interact()?
+            {
+                break;
+            }
+        }
         let date: String = Input::new()
             .with_prompt("Date (JJ/MM/AAAA)")
-            .validate_with(|d: &String| {
-                AutorisationForm::check_date_format(d)
-                    .map(|_| ())
-                    .map_err(|e| e.to_string())
-            })
+            .validate_with(|d: &String| parse_date(d).map(|_| ()).map_err(|e| e.to_string()))
             .interact_text()?;
-        let lieu: String = Input::new()
+        let lieu: String = input_with(defaults.lieu.clone())
             .with_prompt("Lieu")
-            .validate_with(|s: &String| {
-                if s.trim().is_empty() || s.len() > 80 {
-                    Err("lieu vide ou trop long (>80)")
-                } else {
-                    Ok(())
-                }
-            })
-            .interact_text()?;
-        let classe: String = Input::new()
-            .with_prompt("Classe (optionnel)")
-            .allow_empty(true)
+            .validate_with(|s: &String| check_name(s))
             .interact_text()?;
-        let responsable_nom: String = Input::new()
-            .with_prompt("Nom du responsable légal (optionnel)")
+        let debut: String = Input::new()
+            .with_prompt("Heure de début (HH:MM, optionnel)")
             .allow_empty(true)
+            .validate_with(|h: &String| {
+                if h.is_empty() {
+                    return Ok(());
+                }
+                parse_heure(h).map(|_| ()).map_err(|e| e.to_string())
+            })
             .interact_text()?;
-        let responsable_tel: String = Input::new()
-            .with_prompt("Téléphone du responsable (optionnel)")
+        let debut = opt_heure(&debut)?;
+        let fin: String = Input::new()
+            .with_prompt("Heure de fin (HH:MM, optionnel)")
             .allow_empty(true)
+            .validate_with(|h: &String| -> Result<(), String> {
+                let f = opt_heure(h).map_err(|e| e.to_string())?;
+                PlageHoraire::new(debut, f)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            })
             .interact_text()?;
+        let fin = opt_heure(&fin)?;
+        let responsables = prompt_responsables(defaults.signataire.as_ref())?;
+        let contacts_urgence = prompt_contacts_urgence()?;
         let motif: String = Input::new()
             .with_prompt("Motif (optionnel)")
             .allow_empty(true)
             .interact_text()?;
         Ok(AutorisationForm {
-            enfant: Enfant {
-                nom: enfant_nom,
-                prenom: if enfant_prenom.is_empty() {
-                    None
-                } else {
-                    Some(enfant_prenom)
-                },
-            },
-            date,
-            lieu,
-            classe: if classe.is_empty() {
+            enfants,
+            date: parse_date(&date)?,
+            lieu: Name::new(lieu)?,
+            responsables,
+            contacts_urgence,
+            plage_horaire: if debut.is_none() && fin.is_none() {
                 None
             } else {
-                Some(classe)
+                Some(PlageHoraire::new(debut, fin)?)
             },
-            responsable: if responsable_nom.is_empty() && responsable_tel.is_empty() {
-                None
-            } else {
-                Some(Responsable {
-                    nom: responsable_nom,
-                    telephone: if responsable_tel.is_empty() {
-                        None
-                    } else {
-                        Some(responsable_tel)
-                    },
-                })
-            },
-            plage_horaire: None,
-            motif: if motif.is_empty() { None } else { Some(motif) },
+            motif: non_empty(motif),
+            langue: None,
         })
     }
 
+    /// Règles transverses ; les formats (noms, date, heures, téléphones) sont garantis par
+    /// les types du modèle. Renvoie toutes les erreurs d'un coup (voir `report`).
     pub fn validate(&self) -> Result<()> {
-        // nom enfant present, longueur <=80
-        if self.enfant.nom.trim().is_empty() || self.enfant.nom.len() > 80 {
-            anyhow::bail!("Nom de l'enfant absent ou trop long");
+        self.report().into_result()?;
+        Ok(())
+    }
+
+    /// Toutes les violations métier (erreurs et avertissements) du formulaire.
+    pub fn report(&self) -> ValidationReport {
+        let mut r = ValidationReport::default();
+        // au moins un enfant ; classe bornée ; doublons
+        if self.enfants.is_empty() {
+            r.error("enfants", "no_child", "Aucun enfant renseigné");
+        }
+        for (i, enfant) in self.enfants.iter().enumerate() {
+            if enfant.classe.as_ref().is_some_and(|c| c.len() > 40) {
+                r.error(
+                    format!("enfants[{i}].classe"),
+                    "classe_too_long",
+                    "classe trop longue (>40)",
+                );
+            }
+            if self.enfants[..i]
+                .iter()
+                .any(|e| e.nom_complet() == enfant.nom_complet())
+            {
+                r.warning(
+                    format!("enfants[{i}]"),
+                    "duplicate_child",
+                    format!("{} apparaît plusieurs fois", enfant.nom_complet()),
+                );
+            }
         }
-        // date valid JJ/MM/AAAA
-        Self::check_date_format(&self.date).context("date invalid")?;
 
-        // lieu
-        if self.lieu.trim().is_empty() || self.lieu.len() > 80 {
-            anyhow::bail!("Lieu absent ou trop long");
+        if self.date < OffsetDateTime::now_utc().date() {
+            r.warning("date", "date_in_past", "la date de sortie est passée");
         }
 
-        // responsable phone normalization/validation
-        if let Some(resp) = &self.responsable {
-            if let Some(tel) = &resp.telephone {
-                let normalized = Self::normalize_fr_phone(tel)
-                    .context("telephone format invalid (expected FR)")?;
-                if normalized.len() < 8 {
-                    anyhow::bail!("telephone trop court après normalisation");
+        // responsables: email, au moins un signataire
+        for (i, resp) in self.responsables.iter().enumerate() {
+            if let Some(email) = &resp.email {
+                if !Self::is_email(email) {
+                    r.error(
+                        format!("responsables[{i}].email"),
+                        "invalid_email",
+                        format!("email invalide '{email}'"),
+                    );
                 }
             }
         }
-        Ok(())
+        if self.responsables.is_empty() {
+            r.warning(
+                "responsables",
+                "no_guardian",
+                "aucun responsable légal : bloc de signature générique",
+            );
+        } else if !self.responsables.iter().any(|resp| resp.signataire) {
+            r.warning(
+                "responsables",
+                "no_signatory",
+                "aucun responsable n'est signataire : bloc de signature générique",
+            );
+        }
+        r
     }
 
-    fn check_date_format(s: &str) -> Result<()> {
-        // parse JJ/MM/AAAA
-        let parts: Vec<&str> = s.split('/').collect();
-        if parts.len() != 3 {
-            anyhow::bail!("date doit être JJ/MM/AAAA");
+    /// Avertit pour chaque classe absente de `classes` (liste du profil) ; rien si la liste
+    /// est vide.
+    pub fn check_classes(&self, classes: &[String]) -> ValidationReport {
+        let mut r = ValidationReport::default();
+        if classes.is_empty() {
+            return r;
         }
-        let d = parts[0].parse::<u8>().context("jour parse failed")?;
-        let m = parts[1].parse::<u8>().context("mois parse failed")?;
-        let y = parts[2].parse::<i32>().context("annee parse failed")?;
-        Date::from_calendar_date(y, time::Month::try_from(m).context("mois invalide")?, d)
-            .context("date invalide")?;
-        Ok(())
+        for (i, enfant) in self.enfants.iter().enumerate() {
+            if let Some(classe) = &enfant.classe {
+                if !classes
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case(classe.trim()))
+                {
+                    r.warning(
+                        format!("enfants[{i}].classe"),
+                        "unknown_class",
+                        format!(
+                            "classe '{classe}' absente de la liste ({})",
+                            classes.join(", ")
+                        ),
+                    );
+                }
+            }
+        }
+        r
     }
 
-    fn normalize_fr_phone(s: &str) -> Result<String> {
-        // Keep only digits and plus
-        let mut digits = s
-            .chars()
-            .filter(|c| c.is_ascii_digit() || *c == '+')
-            .collect::<String>();
-        if digits.starts_with('0') {
-            digits.remove(0);
-            digits = format!("+33{digits}");
+    pub(crate) fn is_email(s: &str) -> bool {
+        static RE: OnceLock<regex::Regex> = OnceLock::new();
+        RE.get_or_init(|| regex::Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").expect("email regex"))
+            .is_match(s.trim())
+    }
+}
+
+fn check_name(s: &str) -> Result<(), String> {
+    Name::new(s).map(|_| ()).map_err(|e| e.to_string())
+}
+
+fn check_opt_name(s: &str) -> Result<(), String> {
+    opt_name(s.to_string())
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+fn non_empty(s: String) -> Option<String> {
+    if s.trim().is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+fn opt_name(s: String) -> Result<Option<Name>> {
+    non_empty(s).map(Name::new).transpose()
+}
+
+fn opt_heure(s: &str) -> Result<Option<Time>> {
+    if s.trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_heure(s).map(Some)
+    }
+}
+
+fn prompt_classe(classes: &[String]) -> Result<Option<String>> {
+    use dialoguer::{Input, Select};
+    if classes.is_empty() {
+        let classe: String = Input::new()
+            .with_prompt("Classe (optionnel)")
+            .allow_empty(true)
+            .interact_text()?;
+        return Ok(non_empty(classe));
+    }
+    let mut items: Vec<&str> = classes.iter().map(String::as_str).collect();
+    items.push("(aucune)");
+    let choice = Select::new()
+        .with_prompt("Classe")
+        .items(&items)
+        .default(0)
+        .interact()?;
+    Ok(classes.get(choice).cloned())
+}
+
+/// Champ texte pré-rempli avec `default` s'il est renseigné.
+fn input_with<'a>(default: Option<String>) -> dialoguer::Input<'a, String> {
+    let input = dialoguer::Input::new();
+    match default {
+        Some(d) => input.default(d),
+        None => input,
+    }
+}
+
+/// Responsables légaux ; le premier est pré-rempli avec `initial` (signataire par défaut de
+/// la configuration).
+fn prompt_responsables(initial: Option<&Responsable>) -> Result<Vec<Responsable>> {
+    use dialoguer::{Confirm, Select};
+    let mut responsables = Vec::new();
+    loop {
+        let preset = initial.filter(|_| responsables.is_empty());
+        let nom: String = input_with(preset.map(|r| r.nom.to_string()))
+            .with_prompt("Nom du responsable légal (optionnel)")
+            .allow_empty(true)
+            .validate_with(|s: &String| check_opt_name(s))
+            .interact_text()?;
+        let Some(nom) = opt_name(nom)? else {
+            break;
+        };
+        let liens = ["mère", "père", "tuteur", "autre"];
+        let lien = Select::new()
+            .with_prompt("Lien avec l'enfant")
+            .items(liens)
+            .default(
+                preset
+                    .and_then(|r| r.lien)
+                    .and_then(|l| Lien::ALL.iter().position(|x| *x == l))
+                    .unwrap_or(0),
+            )
+            .interact()?;
+        let telephone: String =
+            input_with(preset.and_then(|r| r.telephone.as_ref().map(|t| t.to_string())))
+                .with_prompt("Téléphone du responsable (optionnel)")
+                .allow_empty(true)
+                .validate_with(|t: &String| {
+                    if t.trim().is_empty() {
+                        return Ok(());
+                    }
+                    PhoneNumber::parse(t).map(|_| ()).map_err(|e| e.to_string())
+                })
+                .interact_text()?;
+        let email: String = input_with(preset.and_then(|r| r.email.clone()))
+            .with_prompt("Email du responsable (optionnel)")
+            .allow_empty(true)
+            .validate_with(|e: &String| {
+                if e.trim().is_empty() || AutorisationForm::is_email(e) {
+                    Ok(())
+                } else {
+                    Err(format!("email invalide '{}'", e.trim()))
+                }
+            })
+            .interact_text()?;
+        let adresse: String = input_with(preset.and_then(|r| r.adresse.clone()))
+            .with_prompt("Adresse postale (optionnel)")
+            .allow_empty(true)
+            .interact_text()?;
+        let signataire = Confirm::new()
+            .with_prompt("Ce responsable doit-il signer ?")
+            .default(preset.map_or(true, |r| r.signataire))
+            .interact()?;
+        responsables.push(Responsable {
+            nom,
+            lien: Some(Lien::ALL[lien]),
+            telephone: non_empty(telephone)
+                .map(|t| PhoneNumber::parse(&t))
+                .transpose()?,
+            email: non_empty(email),
+            adresse: non_empty(adresse),
+            signataire,
+        });
+        if !Confirm::new()
+            .with_prompt("Ajouter un autre responsable légal ?")
+            .default(false)
+            .interact()?
+        {
+            break;
         }
-        Ok(digits)
     }
+    Ok(responsables)
+}
+
+fn prompt_contacts_urgence() -> Result<Vec<ContactUrgence>> {
+    use dialoguer::Input;
+    let mut contacts = Vec::new();
+    loop {
+        let nom: String = Input::new()
+            .with_prompt("Contact d'urgence : nom (vide pour terminer)")
+            .allow_empty(true)
+            .validate_with(|s: &String| check_opt_name(s))
+            .interact_text()?;
+        let Some(nom) = opt_name(nom)? else {
+            break;
+        };
+        let lien: String = Input::new()
+            .with_prompt("Lien avec l'enfant (optionnel)")
+            .allow_empty(true)
+            .interact_text()?;
+        let telephone: String = Input::new()
+            .with_prompt("Téléphone du contact")
+            .validate_with(|t: &String| {
+                PhoneNumber::parse(t).map(|_| ()).map_err(|e| e.to_string())
+            })
+            .interact_text()?;
+        contacts.push(ContactUrgence {
+            nom,
+            lien: non_empty(lien),
+            telephone: PhoneNumber::parse(&telephone)?,
+        });
+    }
+    Ok(contacts)
 }
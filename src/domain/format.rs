@@ -1,13 +1,13 @@
-use time::macros::format_description;
-use time::Date;
+use crate::domain::form::PlageHoraire;
+use crate::i18n::date::{format_plage, human_date};
+use crate::i18n::Locale;
 
 pub fn human_date_fr(jj_mm_aaaa: &str) -> String {
-    // expects validated JJ/MM/AAAA
-    let parts: Vec<&str> = jj_mm_aaaa.split('/').collect();
-    let d: i32 = parts[2].parse().unwrap_or(1970);
-    let m: u8 = parts[1].parse().unwrap_or(1);
-    let day: u8 = parts[0].parse().unwrap_or(1);
-    let dt = Date::from_calendar_date(d, time::Month::try_from(m).unwrap(), day).unwrap();
-    let fmt = format_description!("[day] [month repr:long] [year]");
-    dt.format(&fmt).unwrap_or_else(|_| jj_mm_aaaa.to_string())
+    human_date(jj_mm_aaaa, Locale::Fr)
+}
+
+/// Human French time window for a `PlageHoraire`, e.g. "de 14h00 à 16h30".
+/// Returns `None` when no (valid) bound is set.
+pub fn human_plage_fr(plage: &PlageHoraire) -> Option<String> {
+    format_plage(plage, Locale::Fr)
 }
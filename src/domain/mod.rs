@@ -1,2 +1,8 @@
 pub mod form;
 pub mod format;
+pub mod parse;
+pub mod phone;
+pub mod school;
+pub mod source;
+pub mod types;
+pub mod validation;
@@ -0,0 +1,173 @@
+//! Lecture du document source (YAML/JSON) avec position des erreurs, extrait de la ligne
+//! fautive et suggestion du champ le plus proche en cas de faute de frappe.
+use crate::domain::source;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputFormat {
+    Yaml,
+    Json,
+}
+
+impl InputFormat {
+    /// Format déduit de l'extension (`.json`, `.yml`/`.yaml`) ; `None` si elle est inconnue.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        path.as_ref()
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(|e| e.parse().ok())
+    }
+
+    /// `explicit` (option `--input-format`) sinon l'extension de `path`.
+    pub fn resolve(path: impl AsRef<Path>, explicit: Option<Self>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        explicit.or_else(|| Self::from_path(path)).ok_or_else(|| {
+            anyhow::anyhow!(
+                "format de '{}' inconnu (extension .yml, .yaml ou .json attendue) : précisez --input-format yaml|json",
+                path.display()
+            )
+        })
+    }
+}
+
+impl FromStr for InputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Ok(InputFormat::Yaml),
+            "json" => Ok(InputFormat::Json),
+            other => anyhow::bail!("format d'entrée inconnu '{other}' (yaml, json)"),
+        }
+    }
+}
+
+impl fmt::Display for InputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InputFormat::Yaml => "yaml",
+            InputFormat::Json => "json",
+        })
+    }
+}
+
+/// Erreur de syntaxe (ou de structure, pour une lecture typée) du document, positionnée
+/// (1-based) quand le parseur le permet.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseError {
+    pub format: InputFormat,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+    /// Extrait de la source autour de la position, pour l'affichage.
+    #[serde(skip)]
+    pub snippet: Option<String>,
+}
+
+impl ParseError {
+    fn new(format: InputFormat, content: &str, pos: Option<(usize, usize)>, msg: String) -> Self {
+        ParseError {
+            format,
+            line: pos.map(|p| p.0),
+            column: pos.map(|p| p.1),
+            message: with_suggestion(strip_position(msg)),
+            snippet: pos.map(|(l, c)| source::snippet(content, l, c)),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalide", self.format)?;
+        if let (Some(l), Some(c)) = (self.line, self.column) {
+            write!(f, " (ligne {l}, colonne {c})")?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(snippet) = &self.snippet {
+            write!(f, "\n{snippet}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Retire le suffixe " at line X column Y" que les parseurs ajoutent au message.
+fn strip_position(msg: String) -> String {
+    match msg.find(" at line ") {
+        Some(i) => msg[..i].to_string(),
+        None => msg,
+    }
+}
+
+/// Complète "unknown field `prenon`, expected one of `nom`, `prenom`..." par le champ le
+/// plus proche.
+fn with_suggestion(msg: String) -> String {
+    let re = regex::Regex::new(r"unknown field `([^`]*)`, expected (?:one of )?(.*)")
+        .expect("unknown field regex");
+    let Some(caps) = re.captures(&msg) else {
+        return msg;
+    };
+    let expected: Vec<&str> = caps[2]
+        .split(['`', ',', ' '])
+        .filter(|s| !s.is_empty() && *s != "or")
+        .collect();
+    match closest(&caps[1], &expected) {
+        Some(best) => format!("{msg} (vouliez-vous dire `{best}` ?)"),
+        None => msg,
+    }
+}
+
+/// Candidat le plus proche de `word` (distance d'édition), s'il est assez proche pour être
+/// une faute de frappe plausible.
+pub fn closest<'a>(word: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max = (word.chars().count() / 3).max(2);
+    candidates
+        .iter()
+        .map(|c| (levenshtein(word, c), *c))
+        .filter(|(d, _)| *d <= max)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let sub = prev[j] + usize::from(ca != *cb);
+            cur.push(sub.min(prev[j + 1] + 1).min(cur[j] + 1));
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Parse `content` en valeur brute selon `format`.
+pub fn parse_value(content: &str, format: InputFormat) -> Result<Value, ParseError> {
+    deserialize(content, format)
+}
+
+/// Désérialise `content` en `T` selon `format`, sans repli d'un format sur l'autre.
+pub fn deserialize<T: DeserializeOwned>(
+    content: &str,
+    format: InputFormat,
+) -> Result<T, ParseError> {
+    match format {
+        InputFormat::Json => serde_json::from_str(content).map_err(|e| {
+            let pos = (e.line() > 0).then(|| (e.line(), e.column()));
+            ParseError::new(format, content, pos, e.to_string())
+        }),
+        InputFormat::Yaml => serde_yaml::from_str(content).map_err(|e| {
+            let pos = e.location().map(|l| (l.line(), l.column()));
+            ParseError::new(format, content, pos, e.to_string())
+        }),
+    }
+}
@@ -0,0 +1,382 @@
+//! Plans de numérotation (indicatif, longueurs, préfixes) pour valider les numéros au format
+//! E.164 et les afficher au format national. Les numéros nationaux (`0…`) sont interprétés
+//! dans le plan français, DOM compris (`0692…` → `+262692…`).
+use anyhow::Result;
+
+/// Plan de numérotation d'un pays ou territoire.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NumberingPlan {
+    /// Code ISO 3166-1 (`FR`, `RE`, `BE`...).
+    pub region: &'static str,
+    pub name: &'static str,
+    /// Indicatif pays, sans `+`.
+    pub country_code: &'static str,
+    /// Préfixe national composé devant le numéro national significatif (`0`, ou vide).
+    pub national_prefix: &'static str,
+    /// Longueurs admises du numéro national significatif (NSN).
+    pub lengths: &'static [usize],
+    /// Débuts admis du NSN (vide : tous).
+    pub leading: &'static [&'static str],
+    /// Regroupement des chiffres du NSN pour l'affichage : (début du NSN, tailles de groupes).
+    pub groups: &'static [(&'static str, &'static [usize])],
+}
+
+impl NumberingPlan {
+    fn accepts(&self, nsn: &str) -> bool {
+        self.lengths.contains(&nsn.len())
+            && (self.leading.is_empty() || self.leading.iter().any(|p| nsn.starts_with(p)))
+    }
+
+    /// NSN découpé selon `groups` (le premier motif dont le début correspond).
+    fn grouped(&self, nsn: &str) -> String {
+        let sizes = self
+            .groups
+            .iter()
+            .find(|(start, _)| nsn.starts_with(start))
+            .map_or(&[][..], |(_, sizes)| sizes);
+        let mut out = Vec::new();
+        let mut rest = nsn;
+        for &n in sizes {
+            if rest.len() <= n {
+                break;
+            }
+            let (head, tail) = rest.split_at(n);
+            out.push(head);
+            rest = tail;
+        }
+        out.push(rest);
+        out.join(" ")
+    }
+
+    /// Composable depuis la France avec le préfixe national `0` (métropole et DOM).
+    pub fn is_domestic(&self) -> bool {
+        DOMESTIC.contains(&self.region)
+    }
+}
+
+const ALL_DIGITS: &[usize] = &[];
+const PAIRS_FR: &[(&str, &[usize])] = &[("", &[1, 2, 2, 2, 2])];
+const PAIRS_DOM: &[(&str, &[usize])] = &[("", &[3, 2, 2, 2])];
+const DOMESTIC: &[&str] = &["FR", "RE", "YT", "GP", "GF", "MQ"];
+
+/// Plans connus. Les DOM partageant un indicatif se distinguent par le début du NSN.
+pub const PLANS: &[NumberingPlan] = &[
+    NumberingPlan {
+        region: "FR",
+        name: "France",
+        country_code: "33",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &["1", "2", "3", "4", "5", "6", "7", "8", "9"],
+        groups: PAIRS_FR,
+    },
+    NumberingPlan {
+        region: "RE",
+        name: "La Réunion",
+        country_code: "262",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &["262", "263", "692", "693"],
+        groups: PAIRS_DOM,
+    },
+    NumberingPlan {
+        region: "YT",
+        name: "Mayotte",
+        country_code: "262",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &["269", "639"],
+        groups: PAIRS_DOM,
+    },
+    NumberingPlan {
+        region: "GP",
+        name: "Guadeloupe",
+        country_code: "590",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &["590", "690", "691"],
+        groups: PAIRS_DOM,
+    },
+    NumberingPlan {
+        region: "GF",
+        name: "Guyane",
+        country_code: "594",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &["594", "694"],
+        groups: PAIRS_DOM,
+    },
+    NumberingPlan {
+        region: "MQ",
+        name: "Martinique",
+        country_code: "596",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &["596", "696", "697"],
+        groups: PAIRS_DOM,
+    },
+    NumberingPlan {
+        region: "PM",
+        name: "Saint-Pierre-et-Miquelon",
+        country_code: "508",
+        national_prefix: "",
+        lengths: &[6],
+        leading: &[],
+        groups: &[("", &[2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "NC",
+        name: "Nouvelle-Calédonie",
+        country_code: "687",
+        national_prefix: "",
+        lengths: &[6],
+        leading: &[],
+        groups: &[("", &[2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "PF",
+        name: "Polynésie française",
+        country_code: "689",
+        national_prefix: "",
+        lengths: &[8],
+        leading: &[],
+        groups: &[("", &[2, 2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "WF",
+        name: "Wallis-et-Futuna",
+        country_code: "681",
+        national_prefix: "",
+        lengths: &[6],
+        leading: &[],
+        groups: &[("", &[2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "BE",
+        name: "Belgique",
+        country_code: "32",
+        national_prefix: "0",
+        lengths: &[8, 9],
+        leading: &["1", "2", "3", "4", "5", "6", "7", "8", "9"],
+        groups: &[
+            ("4", &[3, 2, 2, 2]),
+            ("2", &[1, 3, 2, 2]),
+            ("3", &[1, 3, 2, 2]),
+            ("9", &[1, 3, 2, 2]),
+            ("", &[2, 2, 2, 2]),
+        ],
+    },
+    NumberingPlan {
+        region: "CH",
+        name: "Suisse",
+        country_code: "41",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &[],
+        groups: &[("", &[2, 3, 2, 2])],
+    },
+    NumberingPlan {
+        region: "LU",
+        name: "Luxembourg",
+        country_code: "352",
+        national_prefix: "",
+        lengths: &[4, 5, 6, 7, 8, 9, 10, 11],
+        leading: &[],
+        groups: &[("", &[3, 3, 3])],
+    },
+    NumberingPlan {
+        region: "MC",
+        name: "Monaco",
+        country_code: "377",
+        national_prefix: "",
+        lengths: &[8, 9],
+        leading: &[],
+        groups: &[("6", &[1, 2, 2, 2, 2]), ("", &[2, 2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "DE",
+        name: "Allemagne",
+        country_code: "49",
+        national_prefix: "0",
+        lengths: &[6, 7, 8, 9, 10, 11, 12, 13],
+        leading: &[],
+        groups: &[("1", &[3]), ("", ALL_DIGITS)],
+    },
+    NumberingPlan {
+        region: "ES",
+        name: "Espagne",
+        country_code: "34",
+        national_prefix: "",
+        lengths: &[9],
+        leading: &["6", "7", "8", "9"],
+        groups: &[("", &[3, 2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "IT",
+        name: "Italie",
+        country_code: "39",
+        national_prefix: "",
+        lengths: &[6, 7, 8, 9, 10, 11],
+        leading: &[],
+        groups: &[("3", &[3, 3]), ("", &[2, 4])],
+    },
+    NumberingPlan {
+        region: "PT",
+        name: "Portugal",
+        country_code: "351",
+        national_prefix: "",
+        lengths: &[9],
+        leading: &[],
+        groups: &[("", &[3, 3, 3])],
+    },
+    NumberingPlan {
+        region: "GB",
+        name: "Royaume-Uni",
+        country_code: "44",
+        national_prefix: "0",
+        lengths: &[9, 10],
+        leading: &[],
+        groups: &[("7", &[4, 6]), ("", &[2, 4, 4])],
+    },
+    NumberingPlan {
+        region: "NL",
+        name: "Pays-Bas",
+        country_code: "31",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &[],
+        groups: &[("6", &[1, 8]), ("", &[2, 7])],
+    },
+    NumberingPlan {
+        region: "MA",
+        name: "Maroc",
+        country_code: "212",
+        national_prefix: "0",
+        lengths: &[9],
+        leading: &["5", "6", "7", "8"],
+        groups: &[("", &[1, 2, 2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "DZ",
+        name: "Algérie",
+        country_code: "213",
+        national_prefix: "0",
+        lengths: &[8, 9],
+        leading: &[],
+        groups: &[("", &[1, 2, 2, 2, 2])],
+    },
+    NumberingPlan {
+        region: "TN",
+        name: "Tunisie",
+        country_code: "216",
+        national_prefix: "",
+        lengths: &[8],
+        leading: &[],
+        groups: &[("", &[2, 3, 3])],
+    },
+    NumberingPlan {
+        region: "US",
+        name: "États-Unis/Canada",
+        country_code: "1",
+        national_prefix: "",
+        lengths: &[10],
+        leading: &["2", "3", "4", "5", "6", "7", "8", "9"],
+        groups: &[("", &[3, 3, 4])],
+    },
+];
+
+/// Numéro validé : forme E.164 et plan reconnu (`None` pour un indicatif hors table,
+/// accepté sur les seules règles de longueur E.164).
+pub struct E164 {
+    pub normalized: String,
+    pub plan: Option<&'static NumberingPlan>,
+}
+
+/// Plans dont l'indicatif préfixe `digits`, indicatif le plus long d'abord.
+fn plans_for(digits: &str) -> Vec<&'static NumberingPlan> {
+    let best = PLANS
+        .iter()
+        .filter(|p| digits.starts_with(p.country_code))
+        .map(|p| p.country_code.len())
+        .max();
+    PLANS
+        .iter()
+        .filter(|p| Some(p.country_code.len()) == best && digits.starts_with(p.country_code))
+        .collect()
+}
+
+fn describe_lengths(lengths: &[usize]) -> String {
+    match lengths {
+        [n] => n.to_string(),
+        [a, b] => format!("{a} ou {b}"),
+        [first, .., last] => format!("{first} à {last}"),
+        [] => "?".into(),
+    }
+}
+
+/// Valide `digits` (chiffres seuls, sans `+`) comme numéro international.
+pub fn international(digits: &str) -> Result<E164> {
+    if digits.len() > 15 {
+        anyhow::bail!("telephone trop long (>15 chiffres)");
+    }
+    let candidates = plans_for(digits);
+    let Some(first) = candidates.first() else {
+        if digits.starts_with('0') || digits.len() < 8 {
+            anyhow::bail!("indicatif pays invalide ou numéro trop court");
+        }
+        return Ok(E164 {
+            normalized: format!("+{digits}"),
+            plan: None,
+        });
+    };
+    let mut nsn = &digits[first.country_code.len()..];
+    // "+33 (0)6 12..." : préfixe national recopié par habitude
+    if !first.national_prefix.is_empty()
+        && nsn.starts_with(first.national_prefix)
+        && !candidates.iter().any(|p| p.accepts(nsn))
+    {
+        nsn = &nsn[first.national_prefix.len()..];
+    }
+    match candidates.iter().find(|p| p.accepts(nsn)) {
+        Some(plan) => Ok(E164 {
+            normalized: format!("+{}{nsn}", plan.country_code),
+            plan: Some(plan),
+        }),
+        None => anyhow::bail!(
+            "numéro invalide pour {} : {} chiffres attendus après +{}",
+            first.name,
+            describe_lengths(first.lengths),
+            first.country_code
+        ),
+    }
+}
+
+/// Valide `digits` comme numéro national français (`0` + 9 chiffres, DOM compris).
+pub fn national_fr(digits: &str) -> Result<E164> {
+    let Some(nsn) = digits.strip_prefix('0') else {
+        anyhow::bail!("numéro national sans 0 initial : ajoutez l'indicatif pays (+32, +41...)");
+    };
+    let plan = PLANS
+        .iter()
+        .filter(|p| p.is_domestic() && p.region != "FR")
+        .find(|p| p.accepts(nsn))
+        .or_else(|| PLANS.iter().find(|p| p.region == "FR" && p.accepts(nsn)));
+    match plan {
+        Some(plan) => Ok(E164 {
+            normalized: format!("+{}{nsn}", plan.country_code),
+            plan: Some(plan),
+        }),
+        None if nsn.len() < 9 => anyhow::bail!("telephone trop court (10 chiffres attendus)"),
+        None => anyhow::bail!("numéro français invalide (0 suivi de 9 chiffres attendu)"),
+    }
+}
+
+/// Affichage national (`06 12 34 56 78`) d'un NSN dans `plan`.
+pub fn format_national(plan: &NumberingPlan, nsn: &str) -> String {
+    format!("{}{}", plan.national_prefix, plan.grouped(nsn))
+}
+
+/// Affichage international (`+32 470 12 34 56`) d'un NSN dans `plan`.
+pub fn format_international(plan: &NumberingPlan, nsn: &str) -> String {
+    format!("+{} {}", plan.country_code, plan.grouped(nsn))
+}
@@ -0,0 +1,109 @@
+// src/domain/school.rs
+#![forbid(unsafe_code)]
+
+//! Profil de l'école : identité reprise dans l'en-tête (papier à en-tête) de chaque document.
+use crate::domain::form::AutorisationForm;
+use crate::domain::types::PhoneNumber;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// École émettrice. Seul le nom est obligatoire ; les autres lignes de l'en-tête
+/// n'apparaissent que si elles sont renseignées.
+///
+/// ```toml
+/// name = "École élémentaire Jean Jaurès"
+/// academy = "Académie de Rennes — Circonscription de Saint-Malo"
+/// address = "12 rue des Écoles\n35400 Saint-Malo"
+/// phone = "02 99 12 34 56"
+/// email = "ce.0350000a@ac-rennes.fr"
+/// logo = "logo.png"        # PNG ou JPEG, relatif au fichier
+/// every_page = true        # en-tête répété sur chaque page
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct School {
+    pub name: String,
+    /// Académie, circonscription ou réseau.
+    pub academy: Option<String>,
+    /// Adresse postale ; une ligne par `\n`.
+    pub address: Option<String>,
+    pub phone: Option<PhoneNumber>,
+    pub email: Option<String>,
+    /// Logo PNG ou JPEG.
+    pub logo: Option<PathBuf>,
+    /// Répète l'en-tête en haut de chaque page (sinon la première seulement).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub every_page: bool,
+}
+
+impl School {
+    /// École réduite à son nom (`--school-name`).
+    pub fn named(name: impl Into<String>) -> Self {
+        School {
+            name: name.into(),
+            ..School::default()
+        }
+    }
+
+    /// Lit un profil TOML (`.toml`) ou YAML (`.yaml`, `.yml`) ; un logo relatif l'est au
+    /// répertoire du fichier.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("lecture du profil d'école {}", path.display()))?;
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        let mut school: School = match ext.as_deref() {
+            Some("toml") => toml::from_str(&content).map_err(anyhow::Error::from),
+            Some("yaml" | "yml") => serde_yaml::from_str(&content).map_err(anyhow::Error::from),
+            _ => anyhow::bail!(
+                "profil d'école {}: extension inconnue (.toml, .yaml, .yml)",
+                path.display()
+            ),
+        }
+        .with_context(|| format!("profil d'école {}", path.display()))?;
+        if let Some(dir) = path.parent() {
+            school.rebase(dir);
+        }
+        school.validate()?;
+        Ok(school)
+    }
+
+    /// Rend le chemin du logo relatif à `dir` (répertoire du fichier qui le déclare).
+    pub fn rebase(&mut self, dir: &Path) {
+        if let Some(logo) = self.logo.take() {
+            self.logo = Some(if logo.is_relative() {
+                dir.join(logo)
+            } else {
+                logo
+            });
+        }
+    }
+
+    /// Nom non vide, courriel plausible, logo PNG ou JPEG.
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            anyhow::bail!("profil d'école: nom vide");
+        }
+        if let Some(email) = &self.email {
+            if !AutorisationForm::is_email(email) {
+                anyhow::bail!("profil d'école: courriel invalide '{email}'");
+            }
+        }
+        if let Some(logo) = &self.logo {
+            let ext = logo
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(str::to_ascii_lowercase);
+            if !matches!(ext.as_deref(), Some("png" | "jpg" | "jpeg")) {
+                anyhow::bail!(
+                    "profil d'école: logo {} (PNG ou JPEG attendu)",
+                    logo.display()
+                );
+            }
+        }
+        Ok(())
+    }
+}
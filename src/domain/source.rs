@@ -0,0 +1,230 @@
+//! Localisation d'un chemin de champ (`responsables[1].telephone`) dans le texte YAML/JSON
+//! d'origine, pour positionner les diagnostics. Simple balayage textuel (les parseurs ne
+//! conservent pas les positions) : au mieux, la position du dernier segment trouvé.
+use serde::Serialize;
+use std::ops::Range;
+
+/// Position 1-based dans le fichier source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SourcePos {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourcePos {
+    pub fn at_offset(src: &str, offset: usize) -> Self {
+        let before = &src[..offset.min(src.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        SourcePos { line, column }
+    }
+}
+
+enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+fn segments(path: &str) -> Vec<Segment<'_>> {
+    let mut out = Vec::new();
+    for part in path.split('.').filter(|p| !p.is_empty()) {
+        let mut pieces = part.split('[');
+        if let Some(key) = pieces.next().filter(|k| !k.is_empty()) {
+            out.push(Segment::Key(key));
+        }
+        for idx in pieces {
+            if let Ok(i) = idx.trim_end_matches(']').parse() {
+                out.push(Segment::Index(i));
+            }
+        }
+    }
+    out
+}
+
+/// Position du champ `path` dans `src`, ou du plus proche parent trouvé.
+pub fn locate(src: &str, path: &str) -> Option<SourcePos> {
+    let mut range = 0..src.len();
+    let mut found = None;
+    for seg in segments(path) {
+        let next = match seg {
+            Segment::Key(k) => find_key(src, range.clone(), k).map(|at| (at, value_end(src, at))),
+            Segment::Index(i) => find_item(src, range.clone(), i),
+        };
+        match next {
+            Some((start, end)) => {
+                found = Some(start);
+                range = start..end;
+            }
+            None => break,
+        }
+    }
+    found.map(|o| SourcePos::at_offset(src, o))
+}
+
+fn line_start(src: &str, offset: usize) -> usize {
+    src[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn indent_of_line(src: &str, offset: usize) -> usize {
+    let start = line_start(src, offset);
+    src[start..].chars().take_while(|c| *c == ' ').count()
+}
+
+/// Profondeur d'imbrication (crochets/accolades hors chaînes) entre `from` et `to`.
+fn bracket_depth(src: &str, from: usize, to: usize) -> i32 {
+    let mut depth = 0;
+    let mut in_str = false;
+    let mut escaped = false;
+    for c in src[from..to].chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_str => escaped = true,
+            '"' => in_str = !in_str,
+            '{' | '[' if !in_str => depth += 1,
+            '}' | ']' if !in_str => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
+
+/// Clé `key` la moins imbriquée de `range` (la première en cas d'égalité).
+fn find_key(src: &str, range: Range<usize>, key: &str) -> Option<usize> {
+    let pattern = format!(
+        r#"(?m)(?:^|[\s{{,\[\-])(["']?{}["']?)\s*:"#,
+        regex::escape(key)
+    );
+    let re = regex::Regex::new(&pattern).ok()?;
+    re.captures_iter(&src[range.clone()])
+        .filter_map(|c| c.get(1))
+        .map(|m| range.start + m.start())
+        .min_by_key(|&at| (bracket_depth(src, range.start, at), indent_of_line(src, at)))
+}
+
+/// Début de la valeur qui suit la clé située en `at` (après les deux-points et les blancs).
+fn value_start(src: &str, at: usize) -> usize {
+    let colon = src[at..].find(':').map_or(src.len(), |i| at + i + 1);
+    let mut pos = colon;
+    let mut in_comment = false;
+    for (i, c) in src[colon..].char_indices() {
+        pos = colon + i;
+        match c {
+            '#' => in_comment = true,
+            '\n' => in_comment = false,
+            c if c.is_whitespace() || in_comment => {}
+            _ => return pos,
+        }
+    }
+    pos
+}
+
+/// Fin (exclue) de la construction ouverte par `[`/`{` en `open`.
+fn matching_close(src: &str, open: usize) -> usize {
+    let mut depth = 0;
+    let mut in_str = false;
+    let mut escaped = false;
+    for (i, c) in src[open..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_str => escaped = true,
+            '"' => in_str = !in_str,
+            '{' | '[' if !in_str => depth += 1,
+            '}' | ']' if !in_str => {
+                depth -= 1;
+                if depth == 0 {
+                    return open + i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    src.len()
+}
+
+/// Fin (exclue) de la valeur de la clé située en `at`.
+fn value_end(src: &str, at: usize) -> usize {
+    let start = value_start(src, at);
+    match src[start..].chars().next() {
+        Some('[') | Some('{') => matching_close(src, start),
+        _ if line_start(src, start) == line_start(src, at) => {
+            src[start..].find('\n').map_or(src.len(), |i| start + i)
+        }
+        // bloc YAML : lignes plus indentées que la clé (ou items `-` au même niveau)
+        _ => {
+            let key_indent = indent_of_line(src, at);
+            let mut offset = line_start(src, start);
+            for line in src[offset..].split_inclusive('\n') {
+                let trimmed = line.trim_start_matches(' ');
+                let indent = line.len() - trimmed.len();
+                let blank = trimmed.trim().is_empty() || trimmed.starts_with('#');
+                if !blank
+                    && (indent < key_indent || (indent == key_indent && !trimmed.starts_with('-')))
+                {
+                    return offset;
+                }
+                offset += line.len();
+            }
+            src.len()
+        }
+    }
+}
+
+/// Début et fin de l'élément `index` de la liste valeur de la clé qui ouvre `range`.
+fn find_item(src: &str, range: Range<usize>, index: usize) -> Option<(usize, usize)> {
+    let start = value_start(src, range.start);
+    match src[start..].chars().next()? {
+        '[' => {
+            let close = matching_close(src, start).min(range.end);
+            let mut depth = 0;
+            let mut in_str = false;
+            let mut escaped = false;
+            let mut items = vec![start + 1];
+            for (i, c) in src[start..close].char_indices() {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' if in_str => escaped = true,
+                    '"' => in_str = !in_str,
+                    '{' | '[' if !in_str => depth += 1,
+                    '}' | ']' if !in_str => depth -= 1,
+                    ',' if !in_str && depth == 1 => items.push(start + i + 1),
+                    _ => {}
+                }
+            }
+            let begin = *items.get(index)?;
+            let end = items.get(index + 1).map_or(close, |e| e - 1);
+            let skip = src[begin..end].len() - src[begin..end].trim_start().len();
+            Some((begin + skip, end))
+        }
+        '-' => {
+            let indent = indent_of_line(src, start);
+            let mut items = Vec::new();
+            let mut offset = line_start(src, start);
+            for line in src[offset..range.end].split_inclusive('\n') {
+                let trimmed = line.trim_start_matches(' ');
+                if line.len() - trimmed.len() == indent && trimmed.starts_with('-') {
+                    let skip = trimmed[1..].len() - trimmed[1..].trim_start().len();
+                    items.push(offset + indent + 1 + skip);
+                }
+                offset += line.len();
+            }
+            let begin = *items.get(index)?;
+            let end = items.get(index + 1).copied().unwrap_or(range.end);
+            Some((begin, end))
+        }
+        _ => None,
+    }
+}
+
+/// Extrait de la ligne `line` de `src` avec un repère sous la colonne `column` :
+///
+/// ```text
+///   3 |     prenon: Léa
+///     |     ^
+/// ```
+pub fn snippet(src: &str, line: usize, column: usize) -> String {
+    let text = src.lines().nth(line.saturating_sub(1)).unwrap_or("");
+    let gutter = line.to_string();
+    let pad = " ".repeat(gutter.len());
+    let caret = " ".repeat(column.saturating_sub(1));
+    format!("{gutter} | {text}\n{pad} | {caret}^")
+}
@@ -0,0 +1,248 @@
+//! Types de valeur validés à la construction : un `Name` vide, un téléphone invalide ou une
+//! date impossible ne peuvent pas exister dans un `AutorisationForm`.
+use crate::domain::phone::{self, NumberingPlan};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+use time::macros::format_description;
+use time::{Date, Time};
+
+/// Longueur maximale (en caractères) d'un `Name`.
+pub const NAME_MAX_LEN: usize = 80;
+
+/// Texte non vide, sans espaces de bord, d'au plus `NAME_MAX_LEN` caractères (nom, lieu...).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Name(String);
+
+impl Name {
+    pub fn new(s: impl Into<String>) -> Result<Self> {
+        let s = s.into();
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            anyhow::bail!("valeur vide");
+        }
+        if trimmed.chars().count() > NAME_MAX_LEN {
+            anyhow::bail!("trop long (>{NAME_MAX_LEN} caractères)");
+        }
+        Ok(Name(trimmed.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Name {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        Name::new(s)
+    }
+}
+
+impl From<Name> for String {
+    fn from(n: Name) -> String {
+        n.0
+    }
+}
+
+impl FromStr for Name {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Name::new(s)
+    }
+}
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Numéro de téléphone validé selon les plans de numérotation de `domain::phone`. Conserve
+/// la saisie d'origine (format fil) et sa forme normalisée E.164 (`+33612345678`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PhoneNumber {
+    raw: String,
+    normalized: String,
+    plan: Option<&'static NumberingPlan>,
+}
+
+impl PhoneNumber {
+    /// Accepte `+CC…` ou `00CC…` (international) et `0…` (national français, DOM compris).
+    pub fn parse(s: &str) -> Result<Self> {
+        let raw = s.trim();
+        if raw
+            .chars()
+            .any(|c| !(c.is_ascii_digit() || " .-()+/".contains(c)))
+        {
+            anyhow::bail!("caractère invalide dans le numéro '{raw}'");
+        }
+        if raw.rfind('+').is_some_and(|i| i > 0) {
+            anyhow::bail!("'+' uniquement en tête du numéro");
+        }
+        let digits = raw.chars().filter(char::is_ascii_digit).collect::<String>();
+        let e164 = if raw.starts_with('+') {
+            phone::international(&digits)?
+        } else if let Some(intl) = digits.strip_prefix("00") {
+            phone::international(intl)?
+        } else {
+            phone::national_fr(&digits)?
+        };
+        Ok(PhoneNumber {
+            raw: raw.to_string(),
+            normalized: e164.normalized,
+            plan: e164.plan,
+        })
+    }
+
+    /// Saisie d'origine.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Forme normalisée E.164 (`+33612345678`).
+    pub fn normalized(&self) -> &str {
+        &self.normalized
+    }
+
+    /// Pays ou territoire reconnu (`FR`, `RE`, `BE`...).
+    pub fn region(&self) -> Option<&'static str> {
+        self.plan.map(|p| p.region)
+    }
+
+    /// Numéro national significatif (sans indicatif ni préfixe national).
+    fn nsn(&self) -> Option<&str> {
+        self.plan
+            .map(|p| &self.normalized[1 + p.country_code.len()..])
+    }
+
+    /// Format national (`06 12 34 56 78`, `0470 12 34 56`).
+    pub fn national(&self) -> String {
+        match (self.plan, self.nsn()) {
+            (Some(plan), Some(nsn)) => phone::format_national(plan, nsn),
+            _ => self.normalized.clone(),
+        }
+    }
+
+    /// Format international (`+33 6 12 34 56 78`).
+    pub fn international(&self) -> String {
+        match (self.plan, self.nsn()) {
+            (Some(plan), Some(nsn)) => phone::format_international(plan, nsn),
+            _ => self.normalized.clone(),
+        }
+    }
+}
+
+impl TryFrom<String> for PhoneNumber {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        PhoneNumber::parse(&s)
+    }
+}
+
+impl From<PhoneNumber> for String {
+    fn from(p: PhoneNumber) -> String {
+        p.raw
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        PhoneNumber::parse(s)
+    }
+}
+
+/// Affichage des documents : format national pour les numéros composables depuis la France
+/// (métropole et DOM), international sinon.
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.plan {
+            Some(plan) if plan.is_domestic() => f.write_str(&self.national()),
+            _ => f.write_str(&self.international()),
+        }
+    }
+}
+
+/// Parse a `JJ/MM/AAAA` string into a `Date`.
+pub fn parse_date(s: &str) -> Result<Date> {
+    let parts: Vec<&str> = s.trim().split('/').collect();
+    if parts.len() != 3 {
+        anyhow::bail!("date doit être JJ/MM/AAAA");
+    }
+    let number = |part: &str, what: &str| {
+        part.parse::<u16>()
+            .map_err(|_| anyhow::anyhow!("{what} invalide '{part}' (nombre attendu)"))
+    };
+    let d = number(parts[0], "jour")?;
+    let m = number(parts[1], "mois")?;
+    let y = number(parts[2], "année")?;
+    let month = u8::try_from(m)
+        .ok()
+        .and_then(|m| time::Month::try_from(m).ok())
+        .ok_or_else(|| anyhow::anyhow!("mois invalide {m} (1 à 12)"))?;
+    let days = month.length(i32::from(y));
+    match u8::try_from(d) {
+        Ok(d) if (1..=days).contains(&d) => Date::from_calendar_date(i32::from(y), month, d)
+            .map_err(|_| anyhow::anyhow!("année invalide {y}")),
+        _ => anyhow::bail!("jour invalide {d} (1 à {days} pour ce mois)"),
+    }
+}
+
+/// Parse an `HH:MM` string (24h) into a `Time`.
+pub fn parse_heure(s: &str) -> Result<Time> {
+    let fmt = format_description!("[hour repr:24]:[minute]");
+    Time::parse(s.trim(), &fmt).context("heure doit être HH:MM")
+}
+
+/// `#[serde(with)]` : `Date` <-> "JJ/MM/AAAA".
+pub mod date_jj_mm_aaaa {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(d: &Date, s: S) -> Result<S::Ok, S::Error> {
+        let fmt = format_description!("[day]/[month]/[year]");
+        let txt = d.format(&fmt).map_err(serde::ser::Error::custom)?;
+        s.serialize_str(&txt)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Date, D::Error> {
+        let s = String::deserialize(d)?;
+        parse_date(&s).map_err(|e| serde::de::Error::custom(format!("date '{s}': {e:#}")))
+    }
+}
+
+/// `#[serde(with)]` : `Option<Time>` <-> "HH:MM".
+pub mod heure_opt {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(t: &Option<Time>, s: S) -> Result<S::Ok, S::Error> {
+        match t {
+            Some(t) => s.serialize_str(&format!("{:02}:{:02}", t.hour(), t.minute())),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Time>, D::Error> {
+        Option::<String>::deserialize(d)?
+            .map(|s| {
+                parse_heure(&s).map_err(|e| serde::de::Error::custom(format!("heure '{s}': {e:#}")))
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,487 @@
+//! Rapport de validation complet : toutes les violations d'un document, avec chemin du champ,
+//! code stable, sévérité et message, au lieu de s'arrêter à la première erreur.
+use crate::domain::form::{AutorisationForm, FormDefaults, Lien};
+use crate::domain::parse::{closest, parse_value, InputFormat, ParseError};
+use crate::domain::source;
+use crate::domain::types::{parse_date, parse_heure, Name, PhoneNumber};
+use crate::i18n::Locale;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// Une violation, ex. `responsables[0].telephone` / `invalid_phone`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    /// Chemin du champ (`enfants[1].nom`, `date`...) ; vide pour le document entier.
+    pub path: String,
+    /// Code stable, utilisable par des scripts.
+    pub code: &'static str,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "<document>"
+        } else {
+            &self.path
+        };
+        write!(
+            f,
+            "{}[{}] {path}: {}",
+            self.severity, self.code, self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ValidationReport {
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    pub fn error(
+        &mut self,
+        path: impl Into<String>,
+        code: &'static str,
+        message: impl Into<String>,
+    ) {
+        self.push(path.into(), code, Severity::Error, message.into());
+    }
+
+    pub fn warning(
+        &mut self,
+        path: impl Into<String>,
+        code: &'static str,
+        message: impl Into<String>,
+    ) {
+        self.push(path.into(), code, Severity::Warning, message.into());
+    }
+
+    fn push(&mut self, path: String, code: &'static str, severity: Severity, message: String) {
+        self.violations.push(Violation {
+            path,
+            code,
+            severity,
+            message,
+        });
+    }
+
+    pub fn extend(&mut self, other: ValidationReport) {
+        self.violations.extend(other.violations);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(|v| v.severity == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(|v| v.severity == Severity::Warning)
+    }
+
+    /// `Err(self)` s'il y a au moins une erreur (les avertissements ne bloquent pas).
+    pub fn into_result(self) -> Result<(), ValidationReport> {
+        if self.has_errors() {
+            Err(self)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.errors().count();
+        write!(f, "{n} erreur(s) de validation")?;
+        for v in &self.violations {
+            write!(f, "\n  {v}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+/// Violation positionnée dans le texte source (ligne/colonne 1-based si retrouvées).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    #[serde(flatten)]
+    pub violation: Violation,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl ValidationReport {
+    /// Associe à chaque violation sa position dans `src`, le texte d'où provient le document.
+    pub fn diagnostics(&self, src: &str) -> Vec<Diagnostic> {
+        self.violations
+            .iter()
+            .map(|v| {
+                let pos = source::locate(src, &v.path);
+                Diagnostic {
+                    violation: v.clone(),
+                    line: pos.map(|p| p.line),
+                    column: pos.map(|p| p.column),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parse puis valide un texte source ; seule une erreur de syntaxe interrompt l'analyse.
+pub fn validate_source(
+    content: &str,
+    format: InputFormat,
+) -> Result<(Option<AutorisationForm>, ValidationReport), ParseError> {
+    validate_input(content, format, &FormDefaults::default(), &[])
+}
+
+/// Comme `validate_source`, après report de `defaults` (configuration) dans les champs
+/// absents ; `classes` (liste du profil) ajoute un avertissement par classe hors liste.
+pub fn validate_input(
+    content: &str,
+    format: InputFormat,
+    defaults: &FormDefaults,
+    classes: &[String],
+) -> Result<(Option<AutorisationForm>, ValidationReport), ParseError> {
+    let mut value = parse_value(content, format)?;
+    defaults.apply(&mut value);
+    let (form, mut report) = validate_document(value);
+    if let Some(form) = &form {
+        report.extend(form.check_classes(classes));
+    }
+    Ok((form, report))
+}
+
+/// Valide un document brut (YAML/JSON déjà parsé) puis, si sa structure est correcte,
+/// le convertit et applique les règles métier. Toutes les violations sont collectées.
+pub fn validate_document(value: Value) -> (Option<AutorisationForm>, ValidationReport) {
+    let mut report = check_document(&value);
+    if report.has_errors() {
+        return (None, report);
+    }
+    match serde_json::from_value::<AutorisationForm>(value) {
+        Ok(form) => {
+            report.extend(form.report());
+            (Some(form), report)
+        }
+        Err(e) => {
+            // filet de sécurité : la vérification structurelle devrait l'avoir détecté
+            report.error("", "invalid_document", e.to_string());
+            (None, report)
+        }
+    }
+}
+
+/// Vérifications structurelles et de format, champ par champ, sur le document brut.
+pub fn check_document(value: &Value) -> ValidationReport {
+    let mut r = ValidationReport::default();
+    let Some(root) = value.as_object() else {
+        r.error("", "invalid_type", "le document doit être un objet");
+        return r;
+    };
+    unknown_fields(
+        &mut r,
+        "",
+        root,
+        &[
+            "enfant",
+            "enfants",
+            "date",
+            "lieu",
+            "classe",
+            "responsable",
+            "responsables",
+            "contacts_urgence",
+            "plage_horaire",
+            "motif",
+            "langue",
+        ],
+    );
+
+    match (root.get("enfant"), root.get("enfants")) {
+        (Some(_), Some(_)) => r.error(
+            "enfants",
+            "conflicting_fields",
+            "`enfant` et `enfants` sont exclusifs",
+        ),
+        (Some(e), None) => check_enfant(&mut r, "enfant", e),
+        (None, Some(list)) => {
+            for (i, e) in items(&mut r, "enfants", list) {
+                check_enfant(&mut r, &format!("enfants[{i}]"), e);
+            }
+        }
+        (None, None) => r.error("enfants", "missing_field", "au moins un enfant est requis"),
+    }
+
+    match root.get("date") {
+        Some(d) => {
+            if let Some(s) = string(&mut r, "date", d) {
+                if let Err(e) = parse_date(s) {
+                    r.error("date", "invalid_date", format!("'{s}': {e:#}"));
+                }
+            }
+        }
+        None => r.error("date", "missing_field", "date requise (JJ/MM/AAAA)"),
+    }
+    match root.get("lieu") {
+        Some(l) => name(&mut r, "lieu", l),
+        None => r.error("lieu", "missing_field", "lieu requis"),
+    }
+    if let Some(c) = root.get("classe") {
+        classe(&mut r, "classe", c);
+    }
+
+    match (root.get("responsable"), root.get("responsables")) {
+        (Some(_), Some(_)) => r.error(
+            "responsables",
+            "conflicting_fields",
+            "`responsable` et `responsables` sont exclusifs",
+        ),
+        (Some(resp), None) => check_responsable(&mut r, "responsable", resp),
+        (None, Some(list)) => {
+            for (i, resp) in items(&mut r, "responsables", list) {
+                check_responsable(&mut r, &format!("responsables[{i}]"), resp);
+            }
+        }
+        (None, None) => {}
+    }
+    if let Some(list) = root.get("contacts_urgence") {
+        for (i, c) in items(&mut r, "contacts_urgence", list) {
+            check_contact(&mut r, &format!("contacts_urgence[{i}]"), c);
+        }
+    }
+    if let Some(p) = root.get("plage_horaire") {
+        check_plage(&mut r, "plage_horaire", p);
+    }
+    if let Some(m) = root.get("motif") {
+        string(&mut r, "motif", m);
+    }
+    if let Some(l) = root.get("langue") {
+        if let Some(s) = string(&mut r, "langue", l) {
+            if let Err(e) = s.parse::<Locale>() {
+                r.error("langue", "invalid_language", e.to_string());
+            }
+        }
+    }
+    r
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn unknown_fields(r: &mut ValidationReport, path: &str, obj: &Map<String, Value>, known: &[&str]) {
+    for key in obj.keys().filter(|k| !known.contains(&k.as_str())) {
+        let message = match closest(key, known) {
+            Some(best) => format!("champ inconnu `{key}` (vouliez-vous dire `{best}` ?)"),
+            None => format!("champ inconnu `{key}`"),
+        };
+        r.error(join(path, key), "unknown_field", message);
+    }
+}
+
+fn object<'a>(
+    r: &mut ValidationReport,
+    path: &str,
+    v: &'a Value,
+) -> Option<&'a Map<String, Value>> {
+    let obj = v.as_object();
+    if obj.is_none() {
+        r.error(path, "invalid_type", "objet attendu");
+    }
+    obj
+}
+
+fn items<'a>(r: &mut ValidationReport, path: &str, v: &'a Value) -> Vec<(usize, &'a Value)> {
+    match v.as_array() {
+        Some(list) => list.iter().enumerate().collect(),
+        None => {
+            r.error(path, "invalid_type", "liste attendue");
+            Vec::new()
+        }
+    }
+}
+
+fn string<'a>(r: &mut ValidationReport, path: &str, v: &'a Value) -> Option<&'a str> {
+    let s = v.as_str();
+    if s.is_none() && !v.is_null() {
+        r.error(
+            path,
+            "invalid_type",
+            "texte attendu (pensez aux guillemets)",
+        );
+    }
+    s
+}
+
+fn name(r: &mut ValidationReport, path: &str, v: &Value) {
+    if let Some(s) = string(r, path, v) {
+        if let Err(e) = Name::new(s) {
+            r.error(path, "invalid_name", e.to_string());
+        }
+    }
+}
+
+fn required_name(r: &mut ValidationReport, path: &str, obj: &Map<String, Value>) {
+    match obj.get("nom") {
+        Some(v) => name(r, &join(path, "nom"), v),
+        None => r.error(join(path, "nom"), "missing_field", "nom requis"),
+    }
+}
+
+fn phone(r: &mut ValidationReport, path: &str, v: &Value) {
+    if let Some(s) = string(r, path, v) {
+        if let Err(e) = PhoneNumber::parse(s) {
+            r.error(path, "invalid_phone", format!("'{s}': {e}"));
+        }
+    }
+}
+
+fn check_enfant(r: &mut ValidationReport, path: &str, v: &Value) {
+    let Some(obj) = object(r, path, v) else {
+        return;
+    };
+    unknown_fields(r, path, obj, &["nom", "prenom", "classe"]);
+    required_name(r, path, obj);
+    if let Some(p) = obj.get("prenom").filter(|p| !p.is_null()) {
+        name(r, &join(path, "prenom"), p);
+    }
+    if let Some(c) = obj.get("classe") {
+        classe(r, &join(path, "classe"), c);
+    }
+}
+
+fn classe(r: &mut ValidationReport, path: &str, v: &Value) {
+    if string(r, path, v).is_some_and(|c| c.len() > 40) {
+        r.error(path, "classe_too_long", "classe trop longue (>40)");
+    }
+}
+
+fn check_responsable(r: &mut ValidationReport, path: &str, v: &Value) {
+    let Some(obj) = object(r, path, v) else {
+        return;
+    };
+    unknown_fields(
+        r,
+        path,
+        obj,
+        &["nom", "lien", "telephone", "email", "adresse", "signataire"],
+    );
+    required_name(r, path, obj);
+    if let Some(l) = obj.get("lien").filter(|l| !l.is_null()) {
+        if serde_json::from_value::<Lien>(l.clone()).is_err() {
+            r.error(
+                join(path, "lien"),
+                "invalid_relationship",
+                format!("lien inconnu {l} (mere, pere, tuteur, autre)"),
+            );
+        }
+    }
+    if let Some(t) = obj.get("telephone").filter(|t| !t.is_null()) {
+        phone(r, &join(path, "telephone"), t);
+    }
+    if let Some(e) = obj.get("email") {
+        let p = join(path, "email");
+        if let Some(email) = string(r, &p, e) {
+            if !AutorisationForm::is_email(email) {
+                r.error(p, "invalid_email", format!("email invalide '{email}'"));
+            }
+        }
+    }
+    if let Some(a) = obj.get("adresse") {
+        string(r, &join(path, "adresse"), a);
+    }
+    if let Some(s) = obj.get("signataire") {
+        if !s.is_boolean() {
+            r.error(
+                join(path, "signataire"),
+                "invalid_type",
+                "booléen attendu (true/false)",
+            );
+        }
+    }
+}
+
+fn check_contact(r: &mut ValidationReport, path: &str, v: &Value) {
+    let Some(obj) = object(r, path, v) else {
+        return;
+    };
+    unknown_fields(r, path, obj, &["nom", "lien", "telephone"]);
+    required_name(r, path, obj);
+    if let Some(l) = obj.get("lien") {
+        string(r, &join(path, "lien"), l);
+    }
+    match obj.get("telephone") {
+        Some(t) => phone(r, &join(path, "telephone"), t),
+        None => r.error(
+            join(path, "telephone"),
+            "missing_field",
+            "téléphone requis pour un contact d'urgence",
+        ),
+    }
+}
+
+fn check_plage(r: &mut ValidationReport, path: &str, v: &Value) {
+    if v.is_null() {
+        return;
+    }
+    let Some(obj) = object(r, path, v) else {
+        return;
+    };
+    unknown_fields(r, path, obj, &["debut", "fin"]);
+    let mut heure = |key: &str| {
+        let p = join(path, key);
+        let s = string(r, &p, obj.get(key)?)?;
+        match parse_heure(s) {
+            Ok(t) => Some(t),
+            Err(e) => {
+                r.error(p, "invalid_time", format!("'{s}': {e:#}"));
+                None
+            }
+        }
+    };
+    let (debut, fin) = (heure("debut"), heure("fin"));
+    if let (Some(d), Some(f)) = (debut, fin) {
+        if d >= f {
+            r.error(
+                join(path, "fin"),
+                "time_range_order",
+                "l'heure de début doit précéder l'heure de fin",
+            );
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Catalogue des libellés traduits utilisés par les renderers.
+use super::Locale;
+use crate::domain::form::Lien;
+
+/// Clé d'un libellé du document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Msg {
+    Title,
+    Child,
+    Date,
+    Place,
+    Times,
+    Class,
+    Guardian,
+    Phone,
+    Email,
+    Address,
+    Emergency,
+    Mother,
+    Father,
+    Tutor,
+    Other,
+    Reason,
+    SignedAt,
+    SignedOn,
+    Signature,
+    KeepPart,
+    CutHere,
+    ReturnSlip,
+    Consent,
+    NoConsent,
+}
+
+/// Libellé brut, sans ponctuation.
+pub fn tr(msg: Msg, locale: Locale) -> &'static str {
+    use Locale::*;
+    use Msg::*;
+    match (msg, locale) {
+        (Title, Fr) => "Autorisation de sortie",
+        (Title, En) => "Permission to leave school",
+        (Title, Es) => "Autorización de salida",
+        (Title, De) => "Ausgangsgenehmigung",
+        (Title, Ar) => "إذن بالخروج",
+
+        (Child, Fr) => "Enfant",
+        (Child, En) => "Child",
+        (Child, Es) => "Alumno/a",
+        (Child, De) => "Kind",
+        (Child, Ar) => "الطفل",
+
+        (Date, Fr) => "Date",
+        (Date, En) => "Date",
+        (Date, Es) => "Fecha",
+        (Date, De) => "Datum",
+        (Date, Ar) => "التاريخ",
+
+        (Place, Fr) => "Lieu",
+        (Place, En) => "Place",
+        (Place, Es) => "Lugar",
+        (Place, De) => "Ort",
+        (Place, Ar) => "المكان",
+
+        (Times, Fr) => "Horaires",
+        (Times, En) => "Times",
+        (Times, Es) => "Horario",
+        (Times, De) => "Uhrzeit",
+        (Times, Ar) => "التوقيت",
+
+        (Class, Fr) => "Classe",
+        (Class, En) => "Class",
+        (Class, Es) => "Clase",
+        (Class, De) => "Klasse",
+        (Class, Ar) => "القسم",
+
+        (Guardian, Fr) => "Responsable légal",
+        (Guardian, En) => "Legal guardian",
+        (Guardian, Es) => "Tutor legal",
+        (Guardian, De) => "Erziehungsberechtigte(r)",
+        (Guardian, Ar) => "الولي الشرعي",
+
+        (Phone, Fr) => "Tél",
+        (Phone, En) => "Phone",
+        (Phone, Es) => "Tel.",
+        (Phone, De) => "Tel.",
+        (Phone, Ar) => "الهاتف",
+
+        (Email, Fr) => "Courriel",
+        (Email, En) => "Email",
+        (Email, Es) => "Correo electrónico",
+        (Email, De) => "E-Mail",
+        (Email, Ar) => "البريد الإلكتروني",
+
+        (Address, Fr) => "Adresse",
+        (Address, En) => "Address",
+        (Address, Es) => "Dirección",
+        (Address, De) => "Adresse",
+        (Address, Ar) => "العنوان",
+
+        (Emergency, Fr) => "Contact d'urgence",
+        (Emergency, En) => "Emergency contact",
+        (Emergency, Es) => "Contacto de emergencia",
+        (Emergency, De) => "Notfallkontakt",
+        (Emergency, Ar) => "جهة الاتصال في حالات الطوارئ",
+
+        (Mother, Fr) => "mère",
+        (Mother, En) => "mother",
+        (Mother, Es) => "madre",
+        (Mother, De) => "Mutter",
+        (Mother, Ar) => "الأم",
+
+        (Father, Fr) => "père",
+        (Father, En) => "father",
+        (Father, Es) => "padre",
+        (Father, De) => "Vater",
+        (Father, Ar) => "الأب",
+
+        (Tutor, Fr) => "tuteur",
+        (Tutor, En) => "guardian",
+        (Tutor, Es) => "tutor",
+        (Tutor, De) => "Vormund",
+        (Tutor, Ar) => "الوصي",
+
+        (Other, Fr) => "autre",
+        (Other, En) => "other",
+        (Other, Es) => "otro",
+        (Other, De) => "Sonstige",
+        (Other, Ar) => "آخر",
+
+        (Reason, Fr) => "Motif",
+        (Reason, En) => "Reason",
+        (Reason, Es) => "Motivo",
+        (Reason, De) => "Grund",
+        (Reason, Ar) => "السبب",
+
+        (SignedAt, Fr) => "Fait à",
+        (SignedAt, En) => "Signed at",
+        (SignedAt, Es) => "En",
+        (SignedAt, De) => "Ort:",
+        (SignedAt, Ar) => "حرر في",
+
+        (SignedOn, Fr) => "le",
+        (SignedOn, En) => "on",
+        (SignedOn, Es) => "a",
+        (SignedOn, De) => "Datum:",
+        (SignedOn, Ar) => "بتاريخ",
+
+        (Signature, Fr) => "Signature du responsable légal",
+        (Signature, En) => "Signature of legal guardian",
+        (Signature, Es) => "Firma del tutor legal",
+        (Signature, De) => "Unterschrift des Erziehungsberechtigten",
+        (Signature, Ar) => "توقيع الولي الشرعي",
+
+        (KeepPart, Fr) => "Partie à conserver par la famille",
+        (KeepPart, En) => "To be kept by the family",
+        (KeepPart, Es) => "Parte para conservar por la familia",
+        (KeepPart, De) => "Von der Familie aufzubewahren",
+        (KeepPart, Ar) => "جزء تحتفظ به العائلة",
+
+        (CutHere, Fr) => "Coupon à découper et à rapporter",
+        (CutHere, En) => "Cut off and return this slip",
+        (CutHere, Es) => "Cupón para recortar y devolver",
+        (CutHere, De) => "Abschnitt abtrennen und zurückgeben",
+        (CutHere, Ar) => "قسيمة تقص وتعاد إلى المدرسة",
+
+        (ReturnSlip, Fr) => "Coupon-réponse",
+        (ReturnSlip, En) => "Reply slip",
+        (ReturnSlip, Es) => "Cupón de respuesta",
+        (ReturnSlip, De) => "Rückmeldeabschnitt",
+        (ReturnSlip, Ar) => "قسيمة الرد",
+
+        (Consent, Fr) => "J'autorise mon enfant à participer à cette sortie.",
+        (Consent, En) => "I give permission for my child to take part in this outing.",
+        (Consent, Es) => "Autorizo a mi hijo/a a participar en esta salida.",
+        (Consent, De) => "Ich erlaube meinem Kind, an diesem Ausflug teilzunehmen.",
+        (Consent, Ar) => "أوافق على مشاركة طفلي في هذه الخرجة.",
+
+        (NoConsent, Fr) => "Je n'autorise pas mon enfant à participer à cette sortie.",
+        (NoConsent, En) => "I do not give permission for my child to take part in this outing.",
+        (NoConsent, Es) => "No autorizo a mi hijo/a a participar en esta salida.",
+        (NoConsent, De) => "Ich erlaube meinem Kind nicht, an diesem Ausflug teilzunehmen.",
+        (NoConsent, Ar) => "لا أوافق على مشاركة طفلي في هذه الخرجة.",
+    }
+}
+
+/// Libellé suivi des deux-points, avec l'espace typographique du français ("Motif :").
+pub fn label(msg: Msg, locale: Locale) -> String {
+    with_colon(tr(msg, locale), locale)
+}
+
+/// Ajoute les deux-points à un texte libre selon les règles de `locale`.
+pub fn with_colon(text: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Fr => format!("{text} :"),
+        _ => format!("{text}:"),
+    }
+}
+
+/// Lien de parenté traduit ("mère", "father"...).
+pub fn lien(l: Lien, locale: Locale) -> &'static str {
+    let msg = match l {
+        Lien::Mere => Msg::Mother,
+        Lien::Pere => Msg::Father,
+        Lien::Tuteur => Msg::Tutor,
+        Lien::Autre => Msg::Other,
+    };
+    tr(msg, locale)
+}
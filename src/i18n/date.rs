@@ -0,0 +1,183 @@
+//! Dates longues et plages horaires localisées ("jeudi 25 septembre 2025", "de 14h00 à 16h30").
+use super::Locale;
+use crate::domain::form::PlageHoraire;
+use crate::domain::types::parse_date;
+use time::{Date, Month, Time, Weekday};
+
+fn weekday_name(w: Weekday, locale: Locale) -> &'static str {
+    let i = w.number_days_from_monday() as usize;
+    let names = match locale {
+        Locale::Fr => [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        Locale::En => [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        Locale::Es => [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        Locale::De => [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        Locale::Ar => [
+            "الاثنين",
+            "الثلاثاء",
+            "الأربعاء",
+            "الخميس",
+            "الجمعة",
+            "السبت",
+            "الأحد",
+        ],
+    };
+    names[i]
+}
+
+fn month_name(m: Month, locale: Locale) -> &'static str {
+    let i = u8::from(m) as usize - 1;
+    let names = match locale {
+        Locale::Fr => [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        Locale::En => [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        Locale::Es => [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        Locale::De => [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        Locale::Ar => [
+            "يناير",
+            "فبراير",
+            "مارس",
+            "أبريل",
+            "مايو",
+            "يونيو",
+            "يوليو",
+            "أغسطس",
+            "سبتمبر",
+            "أكتوبر",
+            "نوفمبر",
+            "ديسمبر",
+        ],
+    };
+    names[i]
+}
+
+/// Date longue avec jour de la semaine, ex. "jeudi 1er octobre 2025" / "Thursday 1 October 2025".
+pub fn format_date_long(date: Date, locale: Locale) -> String {
+    let wd = weekday_name(date.weekday(), locale);
+    let month = month_name(date.month(), locale);
+    let (day, year) = (date.day(), date.year());
+    match locale {
+        Locale::Fr if day == 1 => format!("{wd} 1er {month} {year}"),
+        Locale::Fr | Locale::En | Locale::Ar => format!("{wd} {day} {month} {year}"),
+        Locale::Es => format!("{wd}, {day} de {month} de {year}"),
+        Locale::De => format!("{wd}, {day}. {month} {year}"),
+    }
+}
+
+/// Comme `format_date_long` à partir d'une date JJ/MM/AAAA ; renvoie l'entrée telle quelle si
+/// elle n'est pas valide (les renderers ne doivent jamais paniquer).
+pub fn human_date(jj_mm_aaaa: &str, locale: Locale) -> String {
+    parse_date(jj_mm_aaaa)
+        .map(|d| format_date_long(d, locale))
+        .unwrap_or_else(|_| jj_mm_aaaa.to_string())
+}
+
+/// Heure courte : "14h00" en français, "14:00" ailleurs.
+pub fn format_time(t: Time, locale: Locale) -> String {
+    match locale {
+        Locale::Fr => format!("{}h{:02}", t.hour(), t.minute()),
+        _ => format!("{}:{:02}", t.hour(), t.minute()),
+    }
+}
+
+/// Plage horaire localisée, ex. "de 14h00 à 16h30" / "from 14:00 to 16:30".
+/// Renvoie `None` si aucune borne n'est renseignée.
+pub fn format_plage(plage: &PlageHoraire, locale: Locale) -> Option<String> {
+    let debut = plage.debut().map(|t| format_time(t, locale));
+    let fin = plage.fin().map(|t| format_time(t, locale));
+    let s = match (debut, fin, locale) {
+        (None, None, _) => return None,
+        (Some(d), Some(f), Locale::Fr) => format!("de {d} à {f}"),
+        (Some(d), Some(f), Locale::En) => format!("from {d} to {f}"),
+        (Some(d), Some(f), Locale::Es) => format!("de {d} a {f}"),
+        (Some(d), Some(f), Locale::De) => format!("von {d} bis {f}"),
+        (Some(d), Some(f), Locale::Ar) => format!("من {d} إلى {f}"),
+        (Some(d), None, Locale::Fr) => format!("à partir de {d}"),
+        (Some(d), None, Locale::En) => format!("from {d}"),
+        (Some(d), None, Locale::Es) => format!("desde las {d}"),
+        (Some(d), None, Locale::De) => format!("ab {d}"),
+        (Some(d), None, Locale::Ar) => format!("ابتداءً من {d}"),
+        (None, Some(f), Locale::Fr) => format!("jusqu'à {f}"),
+        (None, Some(f), Locale::En) => format!("until {f}"),
+        (None, Some(f), Locale::Es) => format!("hasta las {f}"),
+        (None, Some(f), Locale::De) => format!("bis {f}"),
+        (None, Some(f), Locale::Ar) => format!("حتى {f}"),
+    };
+    Some(s)
+}
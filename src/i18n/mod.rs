@@ -0,0 +1,76 @@
+//! Localisation: locales, catalogue des libellés et formatage des dates.
+pub mod catalog;
+pub mod date;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Langue de rendu d'un document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Fr,
+    En,
+    Es,
+    De,
+    Ar,
+}
+
+impl Locale {
+    pub const ALL: &'static [Locale] =
+        &[Locale::Fr, Locale::En, Locale::Es, Locale::De, Locale::Ar];
+
+    /// Code ISO 639-1 (`fr`, `en`, ...).
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::Fr => "fr",
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::De => "de",
+            Locale::Ar => "ar",
+        }
+    }
+
+    /// Nom de la langue dans cette langue ("Français", "English"...).
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::Fr => "Français",
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::De => "Deutsch",
+            Locale::Ar => "العربية",
+        }
+    }
+
+    /// Écriture de droite à gauche (arabe).
+    pub fn is_rtl(self) -> bool {
+        matches!(self, Locale::Ar)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = anyhow::Error;
+
+    /// Accepte `fr`, `FR`, `fr-FR`, `fr_BE`...
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lang = s
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Locale::ALL
+            .iter()
+            .copied()
+            .find(|l| l.code() == lang)
+            .ok_or_else(|| anyhow::anyhow!("langue non supportée: '{s}'"))
+    }
+}
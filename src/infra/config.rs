@@ -0,0 +1,407 @@
+//! Fichiers de configuration TOML : celui de l'utilisateur (répertoire de configuration de la
+//! plateforme, par exemple `~/.config/autorisation/config.toml`) et celui du projet
+//! (`autorisation.toml` du répertoire courant, ou `--config`), qui peuvent déclarer des profils
+//! nommés (une école chacun). Ordre de priorité : ligne de commande, variables
+//! d'environnement, profil actif, projet, utilisateur, valeurs par défaut.
+use crate::domain::form::FormDefaults;
+use crate::domain::school::School;
+use crate::i18n::Locale;
+use crate::infra::fs::write_atomic;
+use crate::render::{PageConfig, Theme};
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier de configuration cherché dans le répertoire courant.
+pub const CONFIG_FILE: &str = "autorisation.toml";
+
+/// Nom du fichier dans le répertoire de configuration de l'utilisateur.
+pub const USER_CONFIG_FILE: &str = "config.toml";
+
+/// Réglages lus dans le fichier ; tout est facultatif.
+///
+/// ```toml
+/// theme = "compact"       # compact, standard, large-print ou chemin d'un fichier
+/// lang = "fr"             # langue par défaut (le champ `langue` et --lang priment)
+/// out_dir = "sorties"     # répertoire des documents quand --out est absent
+///
+/// [page]
+/// paper = "a5"            # a4, a5, letter, legal ou "210x297mm"
+/// orientation = "landscape"
+/// margins = "15,20"       # 1 à 4 valeurs en mm, ordre CSS
+///
+/// [school]                # papier à en-tête (voir `School`)
+/// name = "École Jean Jaurès"
+/// logo = "logo.png"       # relatif au fichier de configuration
+///
+/// [defaults]              # champs absents du fichier d'entrée
+/// lieu = "Saint-Malo"
+/// signataire = { nom = "Mme Martin", lien = "mere" }
+///
+/// profile = "ecole-jaures"        # profil actif (`profile use`)
+///
+/// [profiles.ecole-jaures]         # mêmes clés que le fichier, sauf les profils
+/// theme = "large-print"
+/// classes = ["CP", "CE1"]
+/// school = { name = "École Jean Jaurès", logo = "jaures.png" }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Thème (voir `Theme::resolve`) ; un fichier relatif l'est au fichier de configuration.
+    pub theme: Option<String>,
+    pub lang: Option<Locale>,
+    pub out_dir: Option<PathBuf>,
+    /// Classes de l'école : choix proposés en mode interactif, avertissement pour une classe
+    /// hors liste.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub classes: Vec<String>,
+    #[serde(default)]
+    pub page: PageConfig,
+    pub school: Option<School>,
+    #[serde(default)]
+    pub defaults: FormDefaults,
+    /// Profil actif ; après `effective`, celui qui a été appliqué.
+    pub profile: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Config>,
+}
+
+impl Config {
+    /// Lit et analyse `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("lecture de {}", path.display()))?;
+        let mut config =
+            Config::parse(&content).with_context(|| format!("configuration {}", path.display()))?;
+        if let Some(dir) = path.parent() {
+            config.rebase(dir);
+        }
+        Ok(config)
+    }
+
+    /// Rend les chemins (logo, fichier de thème, répertoire de sortie) relatifs à `dir`,
+    /// profils compris.
+    fn rebase(&mut self, dir: &Path) {
+        if let Some(school) = &mut self.school {
+            school.rebase(dir);
+        }
+        if let Some(theme) = self.theme.take() {
+            let path = Path::new(&theme);
+            self.theme = Some(if Theme::builtin(&theme).is_none() && path.is_relative() {
+                dir.join(path).to_string_lossy().into_owned()
+            } else {
+                theme
+            });
+        }
+        if let Some(out_dir) = self.out_dir.take() {
+            self.out_dir = Some(dir.join(out_dir));
+        }
+        for profile in self.profiles.values_mut() {
+            profile.rebase(dir);
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self> {
+        let config: Config = toml::from_str(content)?;
+        config.validate()?;
+        for (name, profile) in &config.profiles {
+            check_profile_name(name)?;
+            if profile.profile.is_some() || !profile.profiles.is_empty() {
+                anyhow::bail!("profil {name}: pas de profil dans un profil");
+            }
+            profile
+                .validate()
+                .with_context(|| format!("profil {name}"))?;
+        }
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(school) = &self.school {
+            school.validate()?;
+        }
+        Ok(())
+    }
+
+    /// `AUT_PROFILE`, `AUT_THEME`, `AUT_LANG`, `AUT_OUT_DIR` et les réglages de page
+    /// (`PageConfig::from_env`) ; les variables vides sont ignorées.
+    pub fn from_env() -> Result<Self> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        Ok(Config {
+            theme: var("AUT_THEME"),
+            lang: var("AUT_LANG")
+                .map(|v| v.parse().context("variable AUT_LANG"))
+                .transpose()?,
+            out_dir: var("AUT_OUT_DIR").map(PathBuf::from),
+            page: PageConfig::from_env()?,
+            profile: var("AUT_PROFILE"),
+            ..Config::default()
+        })
+    }
+
+    /// Complète les réglages absents avec ceux de `fallback` ; le profil d'école et la liste
+    /// des classes se prennent en bloc, les profils de même nom aussi.
+    pub fn or(self, fallback: Config) -> Config {
+        let mut profiles = fallback.profiles;
+        profiles.extend(self.profiles);
+        Config {
+            theme: self.theme.or(fallback.theme),
+            lang: self.lang.or(fallback.lang),
+            out_dir: self.out_dir.or(fallback.out_dir),
+            classes: if self.classes.is_empty() {
+                fallback.classes
+            } else {
+                self.classes
+            },
+            page: self.page.or(fallback.page),
+            school: self.school.or(fallback.school),
+            defaults: self.defaults.or(fallback.defaults),
+            profile: self.profile.or(fallback.profile),
+            profiles,
+        }
+    }
+
+    /// Configuration effective hors ligne de commande : environnement (`from_env`, plus
+    /// `AUT_SCHOOL_NAME` pour le nom de l'école), profil actif, puis fichiers (`discover`).
+    /// `profile` (`--profile`) l'emporte sur `AUT_PROFILE` et sur la clé `profile`.
+    pub fn effective(
+        explicit: Option<&Path>,
+        profile: Option<&str>,
+    ) -> Result<(Self, Vec<PathBuf>)> {
+        let (files_config, files) = Config::discover(explicit)?;
+        let env = Config::from_env()?;
+        let active = profile
+            .map(String::from)
+            .or_else(|| env.profile.clone())
+            .or_else(|| files_config.profile.clone());
+        let mut config = match active {
+            Some(name) => {
+                let Some(selected) = files_config.profiles.get(&name).cloned() else {
+                    anyhow::bail!(
+                        "profil inconnu: '{name}' (profils : {})",
+                        list_or_none(files_config.profiles.keys())
+                    );
+                };
+                let mut config = env.or(selected).or(files_config);
+                config.profile = Some(name);
+                config
+            }
+            None => env.or(files_config),
+        };
+        if let Some(name) = std::env::var("AUT_SCHOOL_NAME")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+        {
+            config = config.with_school_name(&name);
+        }
+        Ok((config, files))
+    }
+
+    /// Fichier de configuration de l'utilisateur (`config.toml` du répertoire de configuration
+    /// de la plateforme), qu'il existe ou non.
+    pub fn user_path() -> Option<PathBuf> {
+        ProjectDirs::from("", "", "autorisation").map(|d| d.config_dir().join(USER_CONFIG_FILE))
+    }
+
+    /// Fichier du projet : `explicit` s'il est donné, sinon `autorisation.toml` du répertoire
+    /// courant s'il existe.
+    pub fn project_path(explicit: Option<&Path>) -> Option<PathBuf> {
+        match explicit {
+            Some(p) => Some(p.to_path_buf()),
+            None => Some(PathBuf::from(CONFIG_FILE)).filter(|p| p.is_file()),
+        }
+    }
+
+    /// Configuration du projet complétée par celle de l'utilisateur, avec les fichiers lus
+    /// (du plus au moins prioritaire). Un fichier `explicit` doit exister.
+    pub fn discover(explicit: Option<&Path>) -> Result<(Self, Vec<PathBuf>)> {
+        let mut config = Config::default();
+        let mut files = Vec::new();
+        if let Some(path) = Config::project_path(explicit) {
+            config = Config::load(&path)?;
+            files.push(path);
+        }
+        if let Some(path) = Config::user_path().filter(|p| p.is_file()) {
+            config = config.or(Config::load(&path)?);
+            files.push(path);
+        }
+        Ok((config, files))
+    }
+
+    /// Remplace (ou crée) le nom de l'école.
+    pub fn with_school_name(mut self, name: &str) -> Config {
+        self.school.get_or_insert_with(School::default).name = name.to_string();
+        self
+    }
+
+    /// Configuration au format TOML.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).context("sérialisation de la configuration")
+    }
+}
+
+/// Noms de profils séparés par des virgules, ou `aucun`.
+fn list_or_none<'a>(names: impl Iterator<Item = &'a String>) -> String {
+    let names: Vec<&str> = names.map(String::as_str).collect();
+    if names.is_empty() {
+        "aucun".into()
+    } else {
+        names.join(", ")
+    }
+}
+
+/// Nom de profil : lettres, chiffres, `-` et `_` (utilisable tel quel en clé TOML).
+fn check_profile_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!("nom de profil invalide: '{name}' (lettres, chiffres, - et _)");
+    }
+    Ok(())
+}
+
+/// Applique `change` au fichier `path` (créé au besoin, avec ses répertoires), qui n'est
+/// réécrit que s'il reste une configuration valide. Commentaires, ordre et mise en forme des
+/// parties non modifiées sont conservés.
+fn edit(path: &Path, change: impl FnOnce(&mut toml_edit::Table) -> Result<()>) -> Result<()> {
+    let mut doc: toml_edit::DocumentMut = match std::fs::read_to_string(path) {
+        Ok(content) => content
+            .parse()
+            .with_context(|| format!("configuration {}", path.display()))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => toml_edit::DocumentMut::new(),
+        Err(e) => return Err(e).with_context(|| format!("lecture de {}", path.display())),
+    };
+    change(doc.as_table_mut())?;
+    let content = doc.to_string();
+    Config::parse(&content)?;
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).with_context(|| format!("création de {}", dir.display()))?;
+    }
+    write_atomic(path, content.as_bytes())
+        .with_context(|| format!("écriture de {}", path.display()))
+}
+
+/// Section `key` de `table`, créée au besoin ; `None` si la clé n'est pas une section.
+fn section<'a>(
+    table: &'a mut dyn toml_edit::TableLike,
+    key: &str,
+) -> Option<&'a mut dyn toml_edit::TableLike> {
+    table
+        .entry(key)
+        .or_insert_with(|| {
+            let mut section = toml_edit::Table::new();
+            section.set_implicit(true);
+            toml_edit::Item::Table(section)
+        })
+        .as_table_like_mut()
+}
+
+/// `toml::Value` -> élément éditable ; les tables deviennent des sections.
+fn item(value: toml::Value) -> toml_edit::Item {
+    match value {
+        toml::Value::Table(table) => {
+            let mut section = toml_edit::Table::new();
+            for (key, value) in table {
+                section.insert(&key, item(value));
+            }
+            toml_edit::Item::Table(section)
+        }
+        value => toml_edit::Item::Value(inline(value)),
+    }
+}
+
+fn inline(value: toml::Value) -> toml_edit::Value {
+    match value {
+        toml::Value::String(s) => s.into(),
+        toml::Value::Integer(i) => i.into(),
+        toml::Value::Float(f) => f.into(),
+        toml::Value::Boolean(b) => b.into(),
+        toml::Value::Datetime(d) => d.to_string().into(),
+        toml::Value::Array(values) => values.into_iter().map(inline).collect(),
+        toml::Value::Table(table) => toml_edit::Value::InlineTable(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, inline(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Écrit `key = value` dans le fichier `path` (créé au besoin) ; les clés imbriquées
+/// s'écrivent avec des points (`school.name`, `page.paper`, `defaults.signataire.nom`,
+/// `profiles.ecole-jaures.theme`). `true`/`false` s'écrivent en booléens, `classes` en liste
+/// (`CP,CE1`), le reste en chaînes. Le fichier n'est modifié que s'il reste une
+/// configuration valide.
+pub fn set_value(path: &Path, key: &str, value: &str) -> Result<()> {
+    let parts: Vec<&str> = key.split('.').map(str::trim).collect();
+    if parts.iter().any(|p| p.is_empty()) {
+        anyhow::bail!("clé invalide: '{key}'");
+    }
+    let (last, parents) = parts.split_last().expect("clé non vide");
+    let value: toml_edit::Value = match (*last, value) {
+        (_, "true") => true.into(),
+        (_, "false") => false.into(),
+        ("classes", v) => v
+            .split(',')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .collect::<toml_edit::Array>()
+            .into(),
+        (_, v) => v.into(),
+    };
+    edit(path, |table| {
+        let mut current: &mut dyn toml_edit::TableLike = table;
+        for part in parents {
+            current = section(current, part).with_context(|| {
+                format!("clé invalide: '{key}' ('{part}' n'est pas une section)")
+            })?;
+        }
+        match current.get_mut(last) {
+            // garde les commentaires et l'alignement de la ligne remplacée
+            Some(toml_edit::Item::Value(old)) => {
+                let decor = old.decor().clone();
+                *old = value;
+                *old.decor_mut() = decor;
+            }
+            _ => {
+                current.insert(last, toml_edit::Item::Value(value));
+            }
+        }
+        Ok(())
+    })
+    .with_context(|| format!("{key}: valeur refusée"))
+}
+
+/// Ajoute le profil `name` au fichier `path` ; refuse un nom déjà pris.
+pub fn create_profile(path: &Path, name: &str, profile: &Config) -> Result<()> {
+    check_profile_name(name)?;
+    let mut value = toml::Value::try_from(profile).context("sérialisation du profil")?;
+    // sections vides (page, defaults) omises
+    if let toml::Value::Table(table) = &mut value {
+        table.retain(|_, v| !v.as_table().is_some_and(toml::Table::is_empty));
+    }
+    edit(path, |table| {
+        let profiles = section(table, "profiles").context("'profiles' n'est pas une section")?;
+        if profiles.contains_key(name) {
+            anyhow::bail!("le profil '{name}' existe déjà dans {}", path.display());
+        }
+        profiles.insert(name, item(value));
+        Ok(())
+    })
+}
+
+/// Active le profil `name` (clé `profile` de `path`) ; il doit être déclaré dans `known`.
+pub fn use_profile(path: &Path, name: &str, known: &Config) -> Result<()> {
+    if !known.profiles.contains_key(name) {
+        anyhow::bail!(
+            "profil inconnu: '{name}' (profils : {})",
+            list_or_none(known.profiles.keys())
+        );
+    }
+    set_value(path, "profile", name)
+}
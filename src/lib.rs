@@ -3,5 +3,6 @@
 
 // Réexporte les modules internes pour que les tests d'intégration utilisent `autorisation::...`
 pub mod domain;
+pub mod i18n;
 pub mod infra;
 pub mod render;
@@ -0,0 +1,867 @@
+// src/render/builtin.rs
+#![forbid(unsafe_code)]
+
+//! Rendu PDF autonome avec `pdf-writer` et les polices standard 14 (Helvetica), que tout
+//! lecteur PDF fournit : aucune police à charger, la génération fonctionne sur une machine nue.
+//! Texte encodé en WinAnsi : les écritures non latines (arabe) ne sont pas prises en charge.
+use crate::domain::form::AutorisationForm;
+use crate::domain::school::School;
+use crate::i18n::catalog::{label, tr, Msg};
+use crate::i18n::Locale;
+use crate::infra::fs::write_atomic;
+use crate::render::logo::{Logo, LOGO_HEIGHT_MM};
+use crate::render::theme::{Color, HeadingAlign, Theme};
+use crate::render::{
+    consents, coupon_fields, fields, letterhead_lines, signatures, BilingualLayout, DocumentLayout,
+    Orientation, PageSetup, PaperSize, RenderOptions,
+};
+use anyhow::{Context, Result};
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use pdf_writer::{Content, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use std::io::Write;
+use std::path::Path;
+
+/// Nom de ressource du logo de l'école.
+const LOGO: Name<'static> = Name(b"Logo");
+
+/// Ciseaux noirs (✂, `a2`) dans l'encodage de ZapfDingbats.
+const SCISSORS: &[u8] = b"\x22";
+
+fn mm(v: f64) -> f32 {
+    (v * 72.0 / 25.4) as f32
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Font {
+    Regular,
+    Bold,
+    Oblique,
+    /// Symboles (ciseaux du coupon), hors WinAnsi.
+    Dingbats,
+}
+
+impl Font {
+    const ALL: [Font; 4] = [Font::Regular, Font::Bold, Font::Oblique, Font::Dingbats];
+
+    fn resource(self) -> Name<'static> {
+        match self {
+            Font::Regular => Name(b"F1"),
+            Font::Bold => Name(b"F2"),
+            Font::Oblique => Name(b"F3"),
+            Font::Dingbats => Name(b"F4"),
+        }
+    }
+
+    fn base_font(self) -> Name<'static> {
+        match self {
+            Font::Regular => Name(b"Helvetica"),
+            Font::Bold => Name(b"Helvetica-Bold"),
+            Font::Oblique => Name(b"Helvetica-Oblique"),
+            Font::Dingbats => Name(b"ZapfDingbats"),
+        }
+    }
+}
+
+/// Chasses Helvetica (AFM, 1/1000 em) des caractères ASCII 32..=126.
+#[rustfmt::skip]
+const HELVETICA: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Chasses Helvetica-Bold des caractères ASCII 32..=126.
+#[rustfmt::skip]
+const HELVETICA_BOLD: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Code WinAnsi (cp1252) de `c`, s'il existe.
+fn winansi(c: char) -> Option<u8> {
+    let code = c as u32;
+    match code {
+        0x20..=0x7E | 0xA0..=0xFF => Some(code as u8),
+        _ => Some(match c {
+            '€' => 0x80,
+            '‚' => 0x82,
+            '„' => 0x84,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '™' => 0x99,
+            'Œ' => 0x8C,
+            'œ' => 0x9C,
+            'Š' => 0x8A,
+            'š' => 0x9A,
+            'Ž' => 0x8E,
+            'ž' => 0x9E,
+            'Ÿ' => 0x9F,
+            '\u{202F}' | '\u{2009}' => 0xA0,
+            _ => return None,
+        }),
+    }
+}
+
+/// Texte encodé en WinAnsi ; les caractères hors jeu deviennent `?`.
+fn encode(s: &str) -> Vec<u8> {
+    s.chars().map(|c| winansi(c).unwrap_or(b'?')).collect()
+}
+
+/// Chasse (1/1000 em) d'un octet WinAnsi ; approximée hors ASCII (lettres accentuées
+/// comprises, qui ont la chasse de leur lettre de base à peu de chose près).
+fn glyph_width(b: u8, font: Font) -> u16 {
+    let table = match font {
+        Font::Bold => &HELVETICA_BOLD,
+        Font::Regular | Font::Oblique | Font::Dingbats => &HELVETICA,
+    };
+    match b {
+        0x20..=0x7E => table[(b - 0x20) as usize],
+        0x97 | 0x85 => 1000,
+        0x8C | 0x9C => 1000,
+        0x91 | 0x92 => 222,
+        0xA0 => 278,
+        0xC0..=0xDE => 722,
+        _ => 556,
+    }
+}
+
+fn text_width(s: &str, font: Font, size: f32) -> f32 {
+    encode(s)
+        .into_iter()
+        .map(|b| glyph_width(b, font) as f32)
+        .sum::<f32>()
+        * size
+        / 1000.0
+}
+
+/// Découpe `s` en lignes d'au plus `width` points (coupure aux espaces).
+fn wrap(s: &str, font: Font, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in s.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        if !line.is_empty() && text_width(&candidate, font, size) > width {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        } else {
+            line = candidate;
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+/// Mise en page descendante, avec saut de page automatique.
+struct Layout {
+    pages: Vec<Content>,
+    content: Content,
+    left: f32,
+    right: f32,
+    top: f32,
+    bottom: f32,
+    y: f32,
+    /// Tailles, interligne et couleurs.
+    t: Theme,
+    /// Couleur du texte en cours (texte courant ou titres).
+    fill: Color,
+    letterhead: Option<Letterhead>,
+}
+
+/// En-tête de l'école : nom, coordonnées et taille du logo (points) s'il y en a un.
+#[derive(Clone)]
+struct Letterhead {
+    name: String,
+    lines: Vec<String>,
+    logo: Option<(f32, f32)>,
+    every_page: bool,
+}
+
+impl Layout {
+    fn new(page: &PageSetup, theme: &Theme) -> Self {
+        let (width, height) = page.size_mm();
+        let m = page.margins;
+        let top = mm(height - m.top);
+        Layout {
+            pages: Vec::new(),
+            content: Content::new(),
+            left: mm(m.left),
+            right: mm(width - m.right),
+            top,
+            bottom: mm(m.bottom),
+            y: top,
+            t: theme.clone(),
+            fill: theme.text_color,
+            letterhead: None,
+        }
+    }
+
+    /// En-tête en haut de la page courante : logo à gauche, nom et coordonnées à côté.
+    fn draw_letterhead(&mut self) {
+        let Some(lh) = self.letterhead.clone() else {
+            return;
+        };
+        let top = self.y;
+        let (mut x, mut width, mut align) = (self.left, self.column_width(), self.t.heading_align);
+        if let Some((w, h)) = lh.logo {
+            self.content
+                .save_state()
+                .transform([w, 0.0, 0.0, h, self.left, top - h])
+                .x_object(LOGO)
+                .restore_state();
+            x += w + 12.0;
+            width -= w + 12.0;
+            align = HeadingAlign::Left;
+        }
+        self.fill = self.t.heading_color;
+        self.aligned_in(x, width, align, Font::Bold, self.t.h2_pt, &lh.name);
+        self.fill = self.t.text_color;
+        for line in &lh.lines {
+            self.aligned_in(x, width, align, Font::Regular, self.t.caption_pt, line);
+        }
+        if let Some((_, h)) = lh.logo {
+            self.y = self.y.min(top - h);
+        }
+        self.gap(self.t.body_pt);
+    }
+
+    fn column_width(&self) -> f32 {
+        self.right - self.left
+    }
+
+    /// Réserve `h` points sous le curseur, sur une nouvelle page si nécessaire.
+    fn reserve(&mut self, h: f32) {
+        if self.y - h < self.bottom && self.y < self.top {
+            let full = std::mem::replace(&mut self.content, Content::new());
+            self.pages.push(full);
+            self.y = self.top;
+            if self.letterhead.as_ref().is_some_and(|lh| lh.every_page) {
+                self.draw_letterhead();
+            }
+        }
+    }
+
+    fn gap(&mut self, h: f32) {
+        self.y -= h;
+    }
+
+    fn show(&mut self, x: f32, baseline: f32, font: Font, size: f32, s: &str) {
+        let [r, g, b] = self.fill.unit();
+        self.content
+            .begin_text()
+            .set_fill_rgb(r, g, b)
+            .set_font(font.resource(), size)
+            .next_line(x, baseline)
+            .show(Str(&encode(s)))
+            .end_text();
+    }
+
+    /// Paragraphe replié dans `[x, x + width]`.
+    fn paragraph(&mut self, x: f32, width: f32, font: Font, size: f32, s: &str) {
+        for line in wrap(s, font, size, width) {
+            self.reserve(size * self.t.line_height);
+            self.y -= size * self.t.line_height;
+            self.show(x, self.y + size * 0.3, font, size, &line);
+        }
+    }
+
+    fn centered(&mut self, font: Font, size: f32, s: &str) {
+        self.aligned(HeadingAlign::Center, font, size, s);
+    }
+
+    fn aligned(&mut self, align: HeadingAlign, font: Font, size: f32, s: &str) {
+        self.aligned_in(self.left, self.column_width(), align, font, size, s);
+    }
+
+    /// Lignes alignées dans `[left, left + width]`.
+    fn aligned_in(
+        &mut self,
+        left: f32,
+        width: f32,
+        align: HeadingAlign,
+        font: Font,
+        size: f32,
+        s: &str,
+    ) {
+        for line in wrap(s, font, size, width) {
+            self.reserve(size * self.t.line_height);
+            self.y -= size * self.t.line_height;
+            let free = width - text_width(&line, font, size);
+            let x = match align {
+                HeadingAlign::Left => left,
+                HeadingAlign::Center => left + free / 2.0,
+                HeadingAlign::Right => left + free,
+            };
+            self.show(x, self.y + size * 0.3, font, size, &line);
+        }
+    }
+
+    /// Titre en gras, aligné et coloré selon le thème.
+    fn heading(&mut self, size: f32, s: &str) {
+        self.fill = self.t.heading_color;
+        self.aligned(self.t.heading_align, Font::Bold, size, s);
+        self.fill = self.t.text_color;
+    }
+
+    /// Ligne "libellé  valeur" : libellé en gras dans une colonne de `label_w` points.
+    fn field(&mut self, label_w: f32, label: &str, value: &str, size: f32) {
+        let top = self.y;
+        self.paragraph(self.left, label_w - 6.0, Font::Bold, size, label);
+        let after_label = self.y;
+        self.y = top;
+        self.paragraph(
+            self.left + label_w,
+            self.column_width() - label_w,
+            Font::Regular,
+            size,
+            value,
+        );
+        self.y = self.y.min(after_label);
+    }
+
+    /// Trait de signature aligné à droite, de longueur `len`.
+    fn signature_line(&mut self, x_end: f32, len: f32) {
+        self.reserve(self.t.body_pt * 3.0);
+        self.y -= self.t.body_pt * 3.0;
+        self.content
+            .set_line_width(0.6)
+            .move_to(x_end - len, self.y)
+            .line_to(x_end, self.y)
+            .stroke();
+    }
+
+    /// Case à cocher devant un paragraphe replié.
+    fn checkbox(&mut self, size: f32, s: &str) {
+        let indent = size * 1.6;
+        self.reserve(size * self.t.line_height);
+        let baseline = self.y - size * self.t.line_height + size * 0.3;
+        self.content
+            .set_line_width(0.6)
+            .rect(self.left, baseline, size * 0.75, size * 0.75)
+            .stroke();
+        self.paragraph(
+            self.left + indent,
+            self.column_width() - indent,
+            Font::Regular,
+            size,
+            s,
+        );
+    }
+
+    /// Pointillé de découpe sur toute la largeur, ciseaux en tête.
+    fn cut_line(&mut self) {
+        self.reserve(self.t.body_pt * self.t.line_height);
+        self.y -= self.t.body_pt * self.t.line_height;
+        let baseline = self.y + self.t.body_pt * 0.3;
+        self.content
+            .begin_text()
+            .set_font(Font::Dingbats.resource(), self.t.body_pt)
+            .next_line(self.left, baseline)
+            .show(Str(SCISSORS))
+            .end_text();
+        let y = baseline + self.t.body_pt * 0.3;
+        self.content
+            .save_state()
+            .set_line_width(0.6)
+            .set_dash_pattern([3.0, 2.0], 0.0)
+            .move_to(self.left + self.t.body_pt * 1.5, y)
+            .line_to(self.right, y)
+            .stroke()
+            .restore_state();
+    }
+
+    fn finish(mut self) -> Vec<Content> {
+        self.pages.push(self.content);
+        self.pages
+    }
+}
+
+fn signed_at_on(loc: Locale) -> String {
+    format!(
+        "{} ______________________, {} ______________",
+        tr(Msg::SignedAt, loc),
+        tr(Msg::SignedOn, loc)
+    )
+}
+
+/// Largeur de la colonne des libellés : le plus long, borné à 40 % de la largeur utile.
+fn label_width(labels: &[String], size: f32, column: f32) -> f32 {
+    labels
+        .iter()
+        .map(|l| text_width(l, Font::Bold, size))
+        .fold(0.0, f32::max)
+        .min(column * 0.4)
+        + 8.0
+}
+
+fn layout_document(form: &AutorisationForm, opts: &RenderOptions, l: &mut Layout) {
+    let loc = opts.locale(form);
+    l.draw_letterhead();
+    l.heading(l.t.h1_pt, tr(Msg::Title, loc));
+    let coupon = opts.layout == DocumentLayout::Coupon;
+    if coupon {
+        l.centered(Font::Oblique, l.t.caption_pt, tr(Msg::KeepPart, loc));
+    }
+
+    let Some(bi) = opts.bilingual else {
+        l.gap(l.t.body_pt * 1.5);
+        let rows = fields(form, loc);
+        let labels: Vec<String> = rows.iter().map(|(m, _)| label(*m, loc)).collect();
+        let label_w = label_width(&labels, l.t.body_pt, l.column_width());
+        for (label, (_, value)) in labels.iter().zip(&rows) {
+            l.field(label_w, label, value, l.t.body_pt);
+            l.gap(l.t.body_pt * 0.3);
+        }
+        if coupon {
+            layout_coupon(form, loc, l);
+        } else {
+            l.gap(l.t.body_pt * 2.0);
+            layout_signatures(form, loc, l);
+        }
+        return;
+    };
+
+    let second = bi.secondary;
+    l.aligned(
+        l.t.heading_align,
+        Font::Oblique,
+        l.t.caption_pt + 2.0,
+        tr(Msg::Title, second),
+    );
+    l.gap(l.t.body_pt * 1.5);
+    let rows = fields(form, loc).into_iter().zip(fields(form, second));
+    let sigs = signatures(form, loc)
+        .into_iter()
+        .zip(signatures(form, second));
+    match bi.layout {
+        BilingualLayout::Columns => {
+            let half = (l.column_width() - 18.0) / 2.0;
+            let x2 = l.left + half + 18.0;
+            let row = |l: &mut Layout, left: &str, font: Font, right: &str| {
+                let top = l.y;
+                l.paragraph(l.left, half, font, l.t.body_pt, left);
+                let after = l.y;
+                l.y = top;
+                l.paragraph(x2, half, Font::Oblique, l.t.caption_pt, right);
+                l.y = l.y.min(after);
+                l.gap(l.t.body_pt * 0.3);
+            };
+            row(l, loc.native_name(), Font::Bold, second.native_name());
+            for ((msg, value), (_, value2)) in rows {
+                row(
+                    l,
+                    &format!("{} {value}", label(msg, loc)),
+                    Font::Regular,
+                    &format!("{} {value2}", label(msg, second)),
+                );
+            }
+            if !coupon {
+                l.gap(l.t.body_pt * 1.5);
+                row(l, &signed_at_on(loc), Font::Regular, &signed_at_on(second));
+                for (sig, sig2) in sigs {
+                    l.gap(l.t.body_pt);
+                    row(l, &sig, Font::Regular, &sig2);
+                    l.signature_line(l.left + half, half * 0.9);
+                }
+            }
+        }
+        BilingualLayout::Stacked => {
+            for ((msg, value), (_, value2)) in rows {
+                let (lw, cw) = (l.left, l.column_width());
+                l.paragraph(
+                    lw,
+                    cw,
+                    Font::Regular,
+                    l.t.body_pt,
+                    &format!("{} {value}", label(msg, loc)),
+                );
+                l.paragraph(
+                    lw,
+                    cw,
+                    Font::Oblique,
+                    l.t.caption_pt,
+                    &format!("{} {value2}", label(msg, second)),
+                );
+                l.gap(l.t.body_pt * 0.4);
+            }
+            if !coupon {
+                l.gap(l.t.body_pt * 1.5);
+                let (lw, cw) = (l.left, l.column_width());
+                l.paragraph(lw, cw, Font::Regular, l.t.body_pt, &signed_at_on(loc));
+                l.paragraph(lw, cw, Font::Oblique, l.t.caption_pt, &signed_at_on(second));
+                for (sig, sig2) in sigs {
+                    l.gap(l.t.body_pt * 1.5);
+                    l.paragraph(lw, cw, Font::Regular, l.t.body_pt, &sig);
+                    l.paragraph(lw, cw, Font::Oblique, l.t.caption_pt, &sig2);
+                    l.signature_line(l.right, cw * 0.45);
+                }
+            }
+        }
+    }
+    if coupon {
+        layout_coupon(form, loc, l);
+    }
+}
+
+fn layout_signatures(form: &AutorisationForm, loc: Locale, l: &mut Layout) {
+    l.paragraph(
+        l.left,
+        l.column_width(),
+        Font::Regular,
+        l.t.body_pt,
+        &signed_at_on(loc),
+    );
+    for sig in signatures(form, loc) {
+        l.gap(l.t.body_pt * 1.5);
+        l.paragraph(l.left, l.column_width(), Font::Regular, l.t.body_pt, &sig);
+        l.signature_line(l.right, l.column_width() * 0.45);
+    }
+}
+
+/// Coupon-réponse calé en bas de page (sur une nouvelle page s'il ne tient plus) :
+/// pointillé de découpe, rappel enfant / date / classe, cases d'accord, signatures.
+fn layout_coupon(form: &AutorisationForm, loc: Locale, l: &mut Layout) {
+    let rows = coupon_fields(form, loc);
+    let sigs = signatures(form, loc).len();
+    // hauteur estimée : en-tête, rappel, cases (sur deux lignes au plus), signatures
+    let lines = 4 + rows.len() + 2 * consents(loc).len() + 1;
+    let height = lines as f32 * l.t.body_pt * l.t.line_height
+        + l.t.body_pt * 4.5
+        + sigs as f32 * l.t.body_pt * (1.5 + l.t.line_height + 3.0);
+    l.gap(l.t.body_pt * 2.0);
+    l.reserve(height);
+    l.y = l.y.min(l.bottom + height);
+
+    l.cut_line();
+    l.centered(Font::Oblique, l.t.caption_pt, tr(Msg::CutHere, loc));
+    l.gap(l.t.body_pt);
+    l.paragraph(
+        l.left,
+        l.column_width(),
+        Font::Bold,
+        l.t.body_pt + 2.0,
+        tr(Msg::ReturnSlip, loc),
+    );
+    l.gap(l.t.body_pt * 0.5);
+    let labels: Vec<String> = rows.iter().map(|(m, _)| label(*m, loc)).collect();
+    let label_w = label_width(&labels, l.t.body_pt, l.column_width());
+    for (label, (_, value)) in labels.iter().zip(&rows) {
+        l.field(label_w, label, value, l.t.body_pt);
+        l.gap(l.t.body_pt * 0.3);
+    }
+    l.gap(l.t.body_pt);
+    for consent in consents(loc) {
+        l.checkbox(l.t.body_pt, consent);
+        l.gap(l.t.body_pt * 0.3);
+    }
+    l.gap(l.t.body_pt);
+    layout_signatures(form, loc, l);
+}
+
+/// Pages (flux de contenu) de `form` mises en page selon `page` ; `logo` est le logo de
+/// l'école déjà décodé.
+fn slip_pages(
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+    page: &PageSetup,
+    logo: Option<&Logo>,
+) -> Result<Vec<Content>> {
+    let loc = opts.locale(form);
+    if let Some(rtl) = [Some(loc), opts.bilingual.map(|b| b.secondary)]
+        .into_iter()
+        .flatten()
+        .find(|l| l.is_rtl())
+    {
+        anyhow::bail!(
+            "langue '{rtl}' non prise en charge par les polices standard : utilisez --backend typst ou genpdf"
+        );
+    }
+    let mut layout = Layout::new(page, &opts.theme);
+    layout.letterhead = opts.letterhead().map(|school: &School| {
+        // logo de 20 mm de haut, au plus 40 % de la largeur utile
+        let logo = logo.map(|logo| {
+            let (w, h) = (mm(logo.width_mm(LOGO_HEIGHT_MM)), mm(LOGO_HEIGHT_MM));
+            let scale = (layout.column_width() * 0.4 / w).min(1.0);
+            (w * scale, h * scale)
+        });
+        Letterhead {
+            name: school.name.clone(),
+            lines: letterhead_lines(school, loc),
+            logo,
+            every_page: school.every_page,
+        }
+    });
+    layout_document(form, opts, &mut layout);
+    Ok(layout.finish())
+}
+
+/// Objets communs du fichier : catalogue, arbre des pages, informations et polices ; les
+/// pages et demi-pages reçoivent les numéros suivants (`alloc`).
+struct Skeleton {
+    pdf: Pdf,
+    tree_id: Ref,
+    font_ids: Vec<Ref>,
+    /// Logo de l'école, déclaré dans les ressources de chaque page.
+    logo_id: Option<Ref>,
+    next: i32,
+}
+
+impl Skeleton {
+    fn new(title: &str, keywords: Option<&str>, logo: Option<&Logo>) -> Self {
+        let mut pdf = Pdf::new();
+        let catalog_id = Ref::new(1);
+        let tree_id = Ref::new(2);
+        let info_id = Ref::new(3);
+        let font_ids: Vec<Ref> = (0..Font::ALL.len() as i32)
+            .map(|i| Ref::new(4 + i))
+            .collect();
+        pdf.catalog(catalog_id).pages(tree_id);
+        let mut info = pdf.document_info(info_id);
+        info.title(TextStr(title)).producer(TextStr("autorisation"));
+        if let Some(keywords) = keywords {
+            info.keywords(TextStr(keywords));
+        }
+        info.finish();
+        for (font, id) in Font::ALL.iter().zip(&font_ids) {
+            let mut type1 = pdf.type1_font(*id);
+            type1.base_font(font.base_font());
+            // ZapfDingbats garde son encodage symbolique intégré
+            if *font != Font::Dingbats {
+                type1.encoding_predefined(Name(b"WinAnsiEncoding"));
+            }
+        }
+        let next = 4 + Font::ALL.len() as i32;
+        let mut doc = Skeleton {
+            pdf,
+            tree_id,
+            font_ids,
+            logo_id: None,
+            next,
+        };
+        if let Some(logo) = logo {
+            doc.logo_id = Some(doc.image(logo));
+        }
+        doc
+    }
+
+    /// Image RGB compressée, avec son canal alpha en masque doux.
+    fn image(&mut self, logo: &Logo) -> Ref {
+        let id = self.alloc();
+        let mask_id = logo.alpha.as_ref().map(|_| self.alloc());
+        let rgb = deflate(&logo.rgb);
+        let mut image = self.pdf.image_xobject(id, &rgb);
+        image.filter(Filter::FlateDecode);
+        image
+            .width(logo.width as i32)
+            .height(logo.height as i32)
+            .bits_per_component(8);
+        image.color_space().device_rgb();
+        if let Some(mask_id) = mask_id {
+            image.s_mask(mask_id);
+        }
+        image.finish();
+        if let (Some(mask_id), Some(alpha)) = (mask_id, &logo.alpha) {
+            let alpha = deflate(alpha);
+            let mut mask = self.pdf.image_xobject(mask_id, &alpha);
+            mask.filter(Filter::FlateDecode);
+            mask.width(logo.width as i32)
+                .height(logo.height as i32)
+                .bits_per_component(8);
+            mask.color_space().device_gray();
+        }
+        id
+    }
+
+    /// Objets externes communs à toutes les pages (logo) suivis de `extra`.
+    fn x_objects<'a>(&self, extra: &[(Name<'a>, Ref)]) -> Vec<(Name<'a>, Ref)> {
+        self.logo_id
+            .map(|id| (LOGO, id))
+            .into_iter()
+            .chain(extra.iter().copied())
+            .collect()
+    }
+
+    fn alloc(&mut self) -> Ref {
+        self.next += 1;
+        Ref::new(self.next - 1)
+    }
+
+    /// Page de `width` × `height` points, de contenu `content` ; `xobjects` nomme les
+    /// demi-pages qu'elle place.
+    fn page(
+        &mut self,
+        width: f32,
+        height: f32,
+        content: &[u8],
+        xobjects: &[(Name<'_>, Ref)],
+    ) -> Ref {
+        let xobjects = self.x_objects(xobjects);
+        let page_id = self.alloc();
+        let content_id = self.alloc();
+        let mut page = self.pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, width, height));
+        page.parent(self.tree_id);
+        page.contents(content_id);
+        shared_resources(&mut page.resources(), &self.font_ids, &xobjects);
+        page.finish();
+        self.pdf.stream(content_id, content);
+        page_id
+    }
+
+    fn finish(mut self, pages: &[Ref]) -> Vec<u8> {
+        self.pdf
+            .pages(self.tree_id)
+            .kids(pages.iter().copied())
+            .count(pages.len() as i32);
+        self.pdf.finish()
+    }
+}
+
+/// Déclare les polices standard et les objets externes `xobjects` dans `resources`.
+fn shared_resources(
+    resources: &mut pdf_writer::writers::Resources<'_>,
+    font_ids: &[Ref],
+    xobjects: &[(Name<'_>, Ref)],
+) {
+    let mut fonts = resources.fonts();
+    for (font, id) in Font::ALL.iter().zip(font_ids) {
+        fonts.pair(font.resource(), *id);
+    }
+    fonts.finish();
+    if !xobjects.is_empty() {
+        let mut names = resources.x_objects();
+        for (name, id) in xobjects {
+            names.pair(*name, *id);
+        }
+    }
+}
+
+/// Données compressées pour un filtre `FlateDecode`.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).expect("compression en mémoire");
+    encoder.finish().expect("compression en mémoire")
+}
+
+/// Logo de l'école décodé, s'il y en a un.
+fn school_logo(opts: &RenderOptions) -> Result<Option<Logo>> {
+    opts.letterhead()
+        .and_then(|s| s.logo.as_deref())
+        .map(Logo::load)
+        .transpose()
+}
+
+/// Document PDF complet, en mémoire.
+pub fn render_pdf_bytes(form: &AutorisationForm, opts: &RenderOptions) -> Result<Vec<u8>> {
+    let (w, h) = opts.page.size_mm();
+    let logo = school_logo(opts)?;
+    let pages = slip_pages(form, opts, &opts.page, logo.as_ref())?;
+    let mut doc = Skeleton::new(
+        tr(Msg::Title, opts.locale(form)),
+        opts.keywords().as_deref(),
+        logo.as_ref(),
+    );
+    let ids: Vec<Ref> = pages
+        .into_iter()
+        .map(|content| doc.page(mm(w), mm(h), &content.finish(), &[]))
+        .collect();
+    Ok(doc.finish(&ids))
+}
+
+/// Demi-page portrait d'une planche A4 paysage (A5 arrondi au demi-millimètre près).
+const HALF_A4: PaperSize = PaperSize::Custom {
+    width_mm: 148.5,
+    height_mm: 210.0,
+};
+/// Longueur des traits de coupe, en mm.
+const CROP_MARK_MM: f64 = 6.0;
+
+/// Imposition deux poses : chaque feuille A4 paysage porte deux demi-pages A5, avec des
+/// traits de coupe au milieu. Un seul formulaire est imprimé en double ; une liste de
+/// formulaires se suit, deux par feuille. Les marges de `opts.page` s'appliquent à chaque
+/// demi-page ; format et orientation sont ignorés.
+pub fn render_two_up_bytes(forms: &[AutorisationForm], opts: &RenderOptions) -> Result<Vec<u8>> {
+    let Some(first) = forms.first() else {
+        anyhow::bail!("aucun formulaire à imposer");
+    };
+    let half = PageSetup {
+        paper: HALF_A4,
+        orientation: Orientation::Portrait,
+        margins: opts.page.margins,
+    };
+    let logo = school_logo(opts)?;
+    let mut slots: Vec<Vec<u8>> = Vec::new();
+    for form in forms {
+        for page in slip_pages(form, opts, &half, logo.as_ref())? {
+            slots.push(page.finish().to_vec());
+        }
+    }
+    if forms.len() == 1 {
+        slots = slots.into_iter().flat_map(|p| [p.clone(), p]).collect();
+    }
+
+    let (half_w, height) = half.size_mm();
+    let (half_w, height) = (mm(half_w), mm(height));
+    let mut doc = Skeleton::new(
+        tr(Msg::Title, opts.locale(first)),
+        opts.keywords().as_deref(),
+        logo.as_ref(),
+    );
+    let shared = doc.x_objects(&[]);
+    let mut xobjects = Vec::new();
+    for slot in &slots {
+        let id = doc.alloc();
+        let mut form_xobject = doc.pdf.form_xobject(id, slot);
+        form_xobject.bbox(Rect::new(0.0, 0.0, half_w, height));
+        shared_resources(&mut form_xobject.resources(), &doc.font_ids, &shared);
+        form_xobject.finish();
+        xobjects.push(id);
+    }
+
+    let names = [Name(b"S1"), Name(b"S2")];
+    let mut sheets = Vec::new();
+    for pair in xobjects.chunks(2) {
+        let mut content = Content::new();
+        for (i, (name, _)) in names.iter().zip(pair).enumerate() {
+            content
+                .save_state()
+                .transform([1.0, 0.0, 0.0, 1.0, half_w * i as f32, 0.0])
+                .x_object(*name)
+                .restore_state();
+        }
+        // traits de coupe en haut et en bas de la ligne de séparation
+        let mark = mm(CROP_MARK_MM);
+        content
+            .set_line_width(0.3)
+            .move_to(half_w, 0.0)
+            .line_to(half_w, mark)
+            .move_to(half_w, height - mark)
+            .line_to(half_w, height)
+            .stroke();
+        let used: Vec<(Name<'_>, Ref)> = names.iter().copied().zip(pair.iter().copied()).collect();
+        sheets.push(doc.page(half_w * 2.0, height, &content.finish(), &used));
+    }
+    Ok(doc.finish(&sheets))
+}
+
+/// Rendu PDF avec les polices standard, écrit atomiquement dans `out`.
+pub fn render_pdf_builtin(form: &AutorisationForm, opts: &RenderOptions, out: &Path) -> Result<()> {
+    let bytes = render_pdf_bytes(form, opts)?;
+    write_atomic(out, &bytes).with_context(|| format!("écriture de {}", out.display()))
+}
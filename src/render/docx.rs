@@ -0,0 +1,173 @@
+// src/render/docx.rs
+#![forbid(unsafe_code)]
+
+//! Export Office Open XML (`.docx`), modifiable dans Word ou LibreOffice.
+use crate::domain::form::AutorisationForm;
+use crate::i18n::catalog::{tr, Msg};
+use crate::i18n::Locale;
+use crate::render::html::escape;
+use crate::render::office::{paragraphs, zip, Align, ParaStyle};
+use crate::render::{Orientation, RenderContext, RenderOptions, Renderer};
+use anyhow::Result;
+use std::io::Write;
+
+const W_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+const REL_NS: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+<Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>
+</Types>
+"#;
+
+/// Moteur `docx` du registre.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DocxRenderer;
+
+impl Renderer for DocxRenderer {
+    fn id(&self) -> &'static str {
+        "docx"
+    }
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        w.write_all(&render_docx(ctx.form, ctx.options)?)?;
+        Ok(())
+    }
+}
+
+/// Millimètres en vingtièmes de point (unité des marges et du format de page).
+fn twips(mm: f64) -> i64 {
+    (mm * 1440.0 / 25.4).round() as i64
+}
+
+/// Document `.docx` complet, en mémoire.
+pub fn render_docx(form: &AutorisationForm, opts: &RenderOptions) -> Result<Vec<u8>> {
+    let loc = opts.locale(form);
+    let rels = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<Relationships xmlns=\"{REL_NS}\">\n<Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument\" Target=\"word/document.xml\"/>\n<Relationship Id=\"rId2\" Type=\"http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties\" Target=\"docProps/core.xml\"/>\n</Relationships>\n"
+    );
+    let document_rels = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<Relationships xmlns=\"{REL_NS}\">\n<Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles\" Target=\"styles.xml\"/>\n</Relationships>\n"
+    );
+    let core = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<cp:coreProperties xmlns:cp=\"http://schemas.openxmlformats.org/package/2006/metadata/core-properties\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><dc:title>{}</dc:title><dc:language>{}</dc:language>{}</cp:coreProperties>\n",
+        escape(tr(Msg::Title, loc)),
+        loc.code(),
+        opts.keywords()
+            .map(|k| format!("<cp:keywords>{}</cp:keywords>", escape(&k)))
+            .unwrap_or_default()
+    );
+    zip(&[
+        ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+        ("_rels/.rels", rels.as_bytes()),
+        ("docProps/core.xml", core.as_bytes()),
+        ("word/_rels/document.xml.rels", document_rels.as_bytes()),
+        ("word/styles.xml", styles_xml(loc).as_bytes()),
+        ("word/document.xml", document_xml(form, opts).as_bytes()),
+    ])
+}
+
+/// Langue d'un passage : `w:val` pour le latin, `w:bidi` pour l'arabe.
+fn lang(loc: Locale) -> String {
+    if loc.is_rtl() {
+        format!("<w:lang w:bidi=\"{}\"/>", loc.code())
+    } else {
+        format!("<w:lang w:val=\"{}\"/>", loc.code())
+    }
+}
+
+fn styles_xml(loc: Locale) -> String {
+    let mut s = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<w:styles xmlns:w=\"{W_NS}\">\n<w:docDefaults><w:rPrDefault><w:rPr><w:sz w:val=\"24\"/><w:szCs w:val=\"24\"/>{}</w:rPr></w:rPrDefault><w:pPrDefault><w:pPr><w:spacing w:after=\"0\"/></w:pPr></w:pPrDefault></w:docDefaults>\n<w:style w:type=\"paragraph\" w:default=\"1\" w:styleId=\"Normal\"><w:name w:val=\"Normal\"/><w:qFormat/></w:style>\n",
+        lang(loc)
+    );
+    for style in ParaStyle::ALL {
+        let (before, after) = style.spacing_mm();
+        let jc = match style.align() {
+            Align::Start => "start",
+            Align::Center => "center",
+            Align::End => "end",
+        };
+        let mut ppr = String::new();
+        if style.keep_with_next() {
+            ppr.push_str("<w:keepNext/>");
+        }
+        ppr.push_str(&format!(
+            "<w:spacing w:before=\"{}\" w:after=\"{}\"/><w:jc w:val=\"{jc}\"/>",
+            twips(before),
+            twips(after)
+        ));
+        if let Some(level) = style.heading_level() {
+            ppr.push_str(&format!("<w:outlineLvl w:val=\"{}\"/>", level - 1));
+        }
+        let mut rpr = String::new();
+        if style.bold() {
+            rpr.push_str("<w:b/><w:bCs/>");
+        }
+        if style.italic() {
+            rpr.push_str("<w:i/><w:iCs/>");
+        }
+        let half_points = style.size_pt() * 2;
+        rpr.push_str(&format!(
+            "<w:sz w:val=\"{half_points}\"/><w:szCs w:val=\"{half_points}\"/>"
+        ));
+        s.push_str(&format!(
+            "<w:style w:type=\"paragraph\" w:customStyle=\"1\" w:styleId=\"{}\"><w:name w:val=\"{}\"/><w:basedOn w:val=\"Normal\"/><w:qFormat/><w:pPr>{ppr}</w:pPr><w:rPr>{rpr}</w:rPr></w:style>\n",
+            style.id(),
+            escape(style.display_name())
+        ));
+    }
+    s.push_str("</w:styles>\n");
+    s
+}
+
+/// Passage de texte ; `bold` pour les libellés.
+fn run(text: &str, loc: Locale, bold: bool) -> String {
+    let mut rpr = String::new();
+    if bold {
+        rpr.push_str("<w:b/><w:bCs/>");
+    }
+    if loc.is_rtl() {
+        rpr.push_str("<w:rtl/>");
+    }
+    rpr.push_str(&lang(loc));
+    format!(
+        "<w:r><w:rPr>{rpr}</w:rPr><w:t xml:space=\"preserve\">{}</w:t></w:r>",
+        escape(text)
+    )
+}
+
+fn document_xml(form: &AutorisationForm, opts: &RenderOptions) -> String {
+    let (width, height) = opts.page.size_mm();
+    let m = opts.page.margins;
+    let orient = match opts.page.orientation {
+        Orientation::Portrait => "portrait",
+        Orientation::Landscape => "landscape",
+    };
+    let mut body = String::new();
+    for p in paragraphs(form, opts) {
+        let bidi = if p.locale.is_rtl() { "<w:bidi/>" } else { "" };
+        body.push_str(&format!(
+            "<w:p><w:pPr><w:pStyle w:val=\"{}\"/>{bidi}</w:pPr>",
+            p.style.id()
+        ));
+        if let Some(label) = &p.label {
+            body.push_str(&run(&format!("{label} "), p.locale, true));
+        }
+        body.push_str(&run(&p.text, p.locale, false));
+        body.push_str("</w:p>\n");
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<w:document xmlns:w=\"{W_NS}\">\n<w:body>\n{body}<w:sectPr><w:pgSz w:w=\"{}\" w:h=\"{}\" w:orient=\"{orient}\"/><w:pgMar w:top=\"{}\" w:right=\"{}\" w:bottom=\"{}\" w:left=\"{}\" w:header=\"0\" w:footer=\"0\" w:gutter=\"0\"/></w:sectPr>\n</w:body>\n</w:document>\n",
+        twips(width),
+        twips(height),
+        twips(m.top),
+        twips(m.right),
+        twips(m.bottom),
+        twips(m.left)
+    )
+}
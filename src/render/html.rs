@@ -0,0 +1,298 @@
+// src/render/html.rs
+#![forbid(unsafe_code)]
+
+//! Rendu HTML autonome : un seul fichier, feuille de style d'impression en ligne (format et
+//! marges du PDF), pour l'aperçu dans un navigateur ou la publication sur l'ENT.
+use crate::domain::form::AutorisationForm;
+use crate::i18n::catalog::{label, tr, Msg};
+use crate::i18n::Locale;
+use crate::render::{
+    consents, coupon_fields, fields, letterhead_lines, signatures, BilingualLayout, DocumentLayout,
+    Margins as PageMargins, PageSetup, RenderContext, RenderOptions, Renderer, Theme,
+};
+use anyhow::Result;
+use std::io::Write;
+
+/// Moteur `html` du registre.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlRenderer;
+
+impl Renderer for HtmlRenderer {
+    fn id(&self) -> &'static str {
+        "html"
+    }
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        w.write_all(render_html(ctx.form, ctx.options)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Échappe `s` pour un contenu texte ou une valeur d'attribut entre guillemets.
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Attributs `lang` et `dir` d'un élément dans la langue `loc`.
+fn lang_attrs(loc: Locale) -> String {
+    let dir = if loc.is_rtl() { "rtl" } else { "ltr" };
+    format!("lang=\"{}\" dir=\"{dir}\"", loc.code())
+}
+
+/// Hauteur réservée à l'en-tête répété sur chaque page imprimée, en mm.
+const HEADER_MM: f64 = 28.0;
+
+/// Police du thème en tête de la pile de polices par défaut.
+fn font_stack(theme: &Theme) -> String {
+    let fallback = "\"Helvetica Neue\", Arial, \"DejaVu Sans\", sans-serif";
+    match &theme.font_family {
+        Some(family) => format!("\"{}\", {fallback}", family.replace(['"', '\\', ';'], "")),
+        None => fallback.to_string(),
+    }
+}
+
+/// Feuille de style ; `repeat_header` réserve en haut de chaque page imprimée la place de
+/// l'en-tête de l'école, placé en position fixe (répété par le navigateur).
+fn stylesheet(page: &PageSetup, theme: &Theme, repeat_header: bool) -> String {
+    let (width, height) = page.size_mm();
+    let content = page.content_width_mm();
+    let PageMargins {
+        top,
+        right,
+        bottom,
+        left,
+    } = page.margins;
+    let fonts = font_stack(theme);
+    let (page_top, repeat) = if repeat_header {
+        (
+            top + HEADER_MM,
+            format!(
+                "@media print {{ .every-page {{ position: fixed; top: -{HEADER_MM}mm; left: 0; right: 0; height: {}mm; }} }}\n",
+                HEADER_MM - 4.0
+            ),
+        )
+    } else {
+        (top, String::new())
+    };
+    let (h1, h2, h3, body, caption) = (
+        theme.h1_pt,
+        theme.h2_pt,
+        theme.h3_pt,
+        theme.body_pt,
+        theme.caption_pt,
+    );
+    let (line_height, text, heading, align) = (
+        theme.line_height,
+        theme.text_color,
+        theme.heading_color,
+        theme.heading_align.keyword(),
+    );
+    format!(
+        "@page {{ size: {width}mm {height}mm; margin: {page_top}mm {right}mm {bottom}mm {left}mm; }}
+:root {{ font-family: {fonts}; font-size: {body}pt; line-height: {line_height}; color: {text}; background: #fff; }}
+body {{ margin: 0; }}
+main {{ max-width: {content}mm; margin: 0 auto; padding: {top}mm 1em; }}
+@media print {{ main {{ max-width: none; padding: 0; }} }}
+.letterhead {{ display: flex; align-items: center; gap: 1em; margin: 0 0 1em; }}
+.letterhead > div {{ flex: 1; text-align: {align}; }}
+.letterhead:has(.logo) > div {{ text-align: start; }}
+.logo {{ height: 20mm; width: auto; }}
+.school {{ font-size: {h2}pt; font-weight: bold; color: {heading}; margin: 0 0 0.3em; }}
+.details {{ font-size: {caption}pt; margin: 0; }}
+h1 {{ text-align: {align}; font-size: {h1}pt; color: {heading}; margin: 0 0 1.2em; }}
+h1 .tr {{ display: block; font-weight: normal; }}
+.tr {{ font-size: {caption}pt; font-style: italic; }}
+.fields {{ display: grid; grid-template-columns: max-content 1fr; gap: 0.6em 1em; margin: 0; }}
+.columns .fields {{ grid-template-columns: max-content 1fr max-content 1fr; }}
+.fields > div {{ display: contents; }}
+dt {{ font-weight: bold; }}
+dd {{ margin: 0; }}
+.signatures {{ margin-top: 2em; }}
+.blank {{ display: inline-block; min-width: 30%; border-bottom: 1px dotted; }}
+.signature {{ margin-top: 1.5em; break-inside: avoid; }}
+.signature p {{ margin: 0; }}
+.line {{ width: 45%; height: 2.5em; margin-inline-start: auto; border-bottom: 1px solid; }}
+.keep {{ text-align: center; font-style: italic; margin: -0.8em 0 1.2em; }}
+.coupon {{ margin-top: 2.5em; break-inside: avoid; }}
+.cut {{ display: flex; align-items: center; gap: 0.5em; font-size: {caption}pt; }}
+.cut::after {{ content: \"\"; flex: 1; border-top: 1px dashed; }}
+.coupon h2 {{ font-size: {h3}pt; color: {heading}; margin: 0.8em 0; }}
+.consents {{ list-style: none; padding: 0; }}
+.consents li::before {{ content: \"☐\"; margin-inline-end: 0.5em; }}
+{repeat}"
+    )
+}
+
+/// Traduction dans un élément `tag` portant sa langue ; vide sans seconde langue.
+fn tr_span(second: Option<Locale>, tag: &str, text: &str) -> String {
+    match second {
+        Some(l) => format!(
+            "<{tag} class=\"tr\" {}>{}</{tag}>",
+            lang_attrs(l),
+            escape(text)
+        ),
+        None => String::new(),
+    }
+}
+
+fn signed_at_on(loc: Locale) -> String {
+    format!(
+        "{} <span class=\"blank\"></span>, {} <span class=\"blank\"></span>",
+        escape(tr(Msg::SignedAt, loc)),
+        escape(tr(Msg::SignedOn, loc))
+    )
+}
+
+/// Document HTML complet : en-tête de l'école, champs en liste de définitions (libellé /
+/// valeur), zone de signature ou coupon-réponse. En bilingue, la traduction porte ses propres `lang`/`dir`.
+pub fn render_html(form: &AutorisationForm, opts: &RenderOptions) -> Result<String> {
+    let loc = opts.locale(form);
+    let second = opts.bilingual.map(|b| b.secondary);
+    let mut s = String::new();
+    s.push_str(&format!(
+        "<!DOCTYPE html>\n<html {}>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n{}<style>\n{}</style>\n</head>\n",
+        lang_attrs(loc),
+        escape(tr(Msg::Title, loc)),
+        opts.keywords()
+            .map(|k| format!("<meta name=\"keywords\" content=\"{}\">\n", escape(&k)))
+            .unwrap_or_default(),
+        stylesheet(
+            &opts.page,
+            &opts.theme,
+            opts.letterhead().is_some_and(|s| s.every_page)
+        )
+    ));
+    let columns = opts
+        .bilingual
+        .is_some_and(|b| b.layout == BilingualLayout::Columns);
+    s.push_str(if columns {
+        "<body class=\"columns\">\n<main>\n"
+    } else {
+        "<body>\n<main>\n"
+    });
+    if let Some(school) = opts.letterhead() {
+        s.push_str(if school.every_page {
+            "<header class=\"letterhead every-page\">\n"
+        } else {
+            "<header class=\"letterhead\">\n"
+        });
+        if let Some(logo) = &school.logo {
+            s.push_str(&format!(
+                "<img class=\"logo\" src=\"{}\" alt=\"\">\n",
+                escape(&logo.display().to_string())
+            ));
+        }
+        s.push_str(&format!(
+            "<div>\n<p class=\"school\">{}</p>\n",
+            escape(&school.name)
+        ));
+        for line in letterhead_lines(school, loc) {
+            s.push_str(&format!("<p class=\"details\">{}</p>\n", escape(&line)));
+        }
+        s.push_str("</div>\n</header>\n");
+    }
+    s.push_str(&format!(
+        "<h1>{}{}</h1>\n",
+        escape(tr(Msg::Title, loc)),
+        second.map_or(String::new(), |l| tr_span(
+            second,
+            "span",
+            tr(Msg::Title, l)
+        ))
+    ));
+    let coupon = opts.layout == DocumentLayout::Coupon;
+    if coupon {
+        s.push_str(&format!(
+            "<p class=\"keep\">{}</p>\n",
+            escape(tr(Msg::KeepPart, loc))
+        ));
+    }
+
+    s.push_str("<dl class=\"fields\">\n");
+    let translated = second.map(|l| fields(form, l));
+    for (i, (msg, value)) in fields(form, loc).into_iter().enumerate() {
+        s.push_str(&format!(
+            "<div><dt>{}</dt><dd>{}</dd>",
+            escape(&label(msg, loc)),
+            escape(&value)
+        ));
+        if let (Some(l), Some((msg2, value2))) =
+            (second, translated.as_ref().and_then(|t| t.get(i)))
+        {
+            s.push_str(&tr_span(second, "dt", &label(*msg2, l)));
+            s.push_str(&tr_span(second, "dd", value2));
+        }
+        s.push_str("</div>\n");
+    }
+    s.push_str("</dl>\n");
+
+    if coupon {
+        push_coupon(&mut s, form, loc);
+    } else {
+        push_signatures(&mut s, form, loc, second);
+    }
+    s.push_str("</main>\n</body>\n</html>\n");
+    Ok(s)
+}
+
+/// Zone de signature : "Fait à..., le..." puis un bloc par signataire.
+fn push_signatures(s: &mut String, form: &AutorisationForm, loc: Locale, second: Option<Locale>) {
+    s.push_str(&format!(
+        "<section class=\"signatures\" aria-label=\"{}\">\n<p>{}</p>\n",
+        escape(tr(Msg::Signature, loc)),
+        signed_at_on(loc)
+    ));
+    if let Some(l) = second {
+        s.push_str(&format!(
+            "<p class=\"tr\" {}>{}</p>\n",
+            lang_attrs(l),
+            signed_at_on(l)
+        ));
+    }
+    let translated = second.map(|l| signatures(form, l));
+    for (i, signature) in signatures(form, loc).into_iter().enumerate() {
+        s.push_str(&format!(
+            "<div class=\"signature\">\n<p>{}</p>\n",
+            escape(&signature)
+        ));
+        if let Some(sig2) = translated.as_ref().and_then(|t| t.get(i)) {
+            s.push_str(&format!("{}\n", tr_span(second, "p", sig2)));
+        }
+        s.push_str("<div class=\"line\" aria-hidden=\"true\"></div>\n</div>\n");
+    }
+    s.push_str("</section>\n");
+}
+
+/// Coupon-réponse : pointillé de découpe, rappel, cases d'accord et signatures.
+fn push_coupon(s: &mut String, form: &AutorisationForm, loc: Locale) {
+    s.push_str(&format!(
+        "<section class=\"coupon\" aria-label=\"{0}\">\n<p class=\"cut\"><span aria-hidden=\"true\">✂</span> {1}</p>\n<h2>{0}</h2>\n<dl class=\"fields\">\n",
+        escape(tr(Msg::ReturnSlip, loc)),
+        escape(tr(Msg::CutHere, loc))
+    ));
+    for (msg, value) in coupon_fields(form, loc) {
+        s.push_str(&format!(
+            "<div><dt>{}</dt><dd>{}</dd></div>\n",
+            escape(&label(msg, loc)),
+            escape(&value)
+        ));
+    }
+    s.push_str("</dl>\n<ul class=\"consents\">\n");
+    for consent in consents(loc) {
+        s.push_str(&format!("<li>{}</li>\n", escape(consent)));
+    }
+    s.push_str("</ul>\n");
+    push_signatures(s, form, loc, None);
+    s.push_str("</section>\n");
+}
@@ -0,0 +1,56 @@
+// src/render/logo.rs
+#![forbid(unsafe_code)]
+
+//! Logo de l'école décodé une fois (PNG, JPEG) pour les moteurs PDF intégrés.
+use anyhow::{Context, Result};
+use image::{DynamicImage, RgbImage};
+use std::path::Path;
+
+/// Hauteur du logo dans l'en-tête, en mm.
+pub(crate) const LOGO_HEIGHT_MM: f64 = 20.0;
+
+/// Pixels RGB du logo, transparence à part (masque PDF).
+pub(crate) struct Logo {
+    pub width: u32,
+    pub height: u32,
+    pub rgb: Vec<u8>,
+    /// Canal alpha, absent si l'image est opaque.
+    pub alpha: Option<Vec<u8>>,
+}
+
+impl Logo {
+    pub fn load(path: &Path) -> Result<Self> {
+        let img =
+            image::open(path).with_context(|| format!("lecture du logo {}", path.display()))?;
+        let rgba = img.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        let alpha: Vec<u8> = rgba.pixels().map(|p| p[3]).collect();
+        let opaque = alpha.iter().all(|&a| a == 255);
+        Ok(Logo {
+            width,
+            height,
+            rgb: rgba.pixels().flat_map(|p| [p[0], p[1], p[2]]).collect(),
+            alpha: (!opaque).then_some(alpha),
+        })
+    }
+
+    /// Largeur en mm pour une hauteur de `height_mm`.
+    pub fn width_mm(&self, height_mm: f64) -> f64 {
+        height_mm * self.width as f64 / self.height.max(1) as f64
+    }
+
+    /// Image opaque, transparence aplatie sur fond blanc (genpdf refuse le canal alpha).
+    pub fn flattened(&self) -> DynamicImage {
+        let mut rgb = self.rgb.clone();
+        if let Some(alpha) = &self.alpha {
+            for (px, &a) in rgb.chunks_mut(3).zip(alpha) {
+                for c in px {
+                    *c = ((*c as u32 * a as u32 + 255 * (255 - a as u32)) / 255) as u8;
+                }
+            }
+        }
+        DynamicImage::ImageRgb8(
+            RgbImage::from_raw(self.width, self.height, rgb).expect("dimensions du logo"),
+        )
+    }
+}
@@ -1,34 +1,41 @@
 use crate::domain::form::AutorisationForm;
-use crate::domain::format::human_date_fr;
+use crate::render::template::{render_with_default, DEFAULT_MARKDOWN};
+use crate::render::{RenderContext, RenderOptions, Renderer};
 use anyhow::Result;
+use std::io::Write;
 
-pub fn render_markdown(form: &AutorisationForm, school_name: Option<&str>) -> Result<String> {
-    let mut s = String::new();
-    if let Some(school) = school_name {
-        s.push_str(&format!("# {school}\n\n"));
-    }
-    s.push_str("## Autorisation de sortie\n\n");
-    s.push_str(&format!(
-        "**Enfant :** {} {}\n\n",
-        form.enfant.nom,
-        form.enfant.prenom.clone().unwrap_or_default()
-    ));
-    s.push_str(&format!("**Date :** {}\n\n", human_date_fr(&form.date)));
-    s.push_str(&format!("**Lieu :** {}\n\n", form.lieu));
-    if let Some(classe) = &form.classe {
-        s.push_str(&format!("**Classe :** {classe}\n\n"));
-    }
-    if let Some(resp) = &form.responsable {
-        s.push_str(&format!("**Responsable légal :** {}\n\n", resp.nom));
-        if let Some(t) = &resp.telephone {
-            s.push_str(&format!("**Tél :** {t}\n\n"));
-        }
+/// Moteur `md` du registre.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn id(&self) -> &'static str {
+        "md"
     }
-    if let Some(m) = &form.motif {
-        s.push_str(&format!("**Motif :** {m}\n\n"));
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        render_markdown_to_writer(ctx.form, ctx.options, w)
     }
-    s.push_str(
-        "\n\nFait à ____, le ____\n\n\nSignature du responsable légal : ___________________\n",
-    );
-    Ok(s)
+}
+
+/// Écrit le Markdown de `form` dans `w`.
+pub fn render_markdown_to_writer(
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+    mut w: impl Write,
+) -> Result<()> {
+    w.write_all(render_markdown(form, opts)?.as_bytes())?;
+    w.flush()?;
+    Ok(())
+}
+
+/// Markdown de `form` en mémoire, encodé en UTF-8.
+pub fn render_markdown_to_vec(form: &AutorisationForm, opts: &RenderOptions) -> Result<Vec<u8>> {
+    render_markdown(form, opts).map(String::into_bytes)
+}
+
+/// Markdown de `form` : gabarit `--template md=…` (`opts.templates`), sinon
+/// `template::DEFAULT_MARKDOWN`.
+pub fn render_markdown(form: &AutorisationForm, opts: &RenderOptions) -> Result<String> {
+    render_with_default("md", DEFAULT_MARKDOWN, form, opts)
 }
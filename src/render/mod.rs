@@ -1,2 +1,407 @@
+pub mod builtin;
+pub mod docx;
+pub mod html;
+mod logo;
 pub mod markdown;
+pub mod odt;
+mod office;
+pub mod page;
 pub mod pdf;
+pub mod renderer;
+pub mod template;
+pub mod text;
+pub mod theme;
+pub mod typst;
+
+pub use page::{Margins, Orientation, PageConfig, PageSetup, PaperSize};
+pub use renderer::{Registry, RenderContext, Renderer};
+pub use template::Template;
+pub use theme::Theme;
+
+use crate::domain::form::{AutorisationForm, Enfant, Responsable};
+use crate::domain::school::School;
+use crate::i18n::catalog::{label, lien, tr, with_colon, Msg};
+use crate::i18n::date::{format_date_long, format_plage};
+use crate::i18n::Locale;
+use crate::infra::fs::write_atomic;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use tracing::warn;
+
+/// Options de rendu partagées par les backends.
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// École émettrice : nom seul ou papier à en-tête complet (logo, adresse, contacts).
+    pub school: Option<School>,
+    /// Langue forcée (`--lang`) ; à défaut `form.langue`, puis le français.
+    pub lang: Option<Locale>,
+    /// Document bilingue : la langue principale (juridiquement opposable) plus une seconde.
+    pub bilingual: Option<Bilingual>,
+    /// Bloc unique ou information + coupon-réponse détachable.
+    pub layout: DocumentLayout,
+    /// Placement des pages sur la feuille (PDF uniquement).
+    pub imposition: Imposition,
+    /// Format, orientation et marges de la page.
+    pub page: PageSetup,
+    /// Tailles, couleurs et police (PDF, HTML).
+    pub theme: Theme,
+    /// Profil de configuration actif (`--profile`), inscrit dans les métadonnées.
+    pub profile: Option<String>,
+    /// Gabarits des sorties Markdown et texte (`--template`), par id de renderer (`md`,
+    /// `txt`) ; à défaut, ceux de la crate.
+    pub templates: BTreeMap<String, Template>,
+}
+
+impl RenderOptions {
+    /// Langue effective du document pour `form`.
+    pub fn locale(&self, form: &AutorisationForm) -> Locale {
+        self.lang.or(form.langue).unwrap_or_default()
+    }
+
+    /// Mot-clé des métadonnées du document : `profil:<nom>` du profil actif.
+    pub fn keywords(&self) -> Option<String> {
+        self.profile.as_ref().map(|p| format!("profil:{p}"))
+    }
+
+    /// École de l'en-tête, si elle a un nom.
+    pub fn letterhead(&self) -> Option<&School> {
+        self.school.as_ref().filter(|s| !s.name.trim().is_empty())
+    }
+}
+
+/// Mise en page bilingue : chaque libellé dans la langue principale et dans `secondary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bilingual {
+    pub secondary: Locale,
+    pub layout: BilingualLayout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BilingualLayout {
+    /// Ligne principale, puis traduction en dessous (plus petite).
+    #[default]
+    Stacked,
+    /// Deux colonnes côte à côte.
+    Columns,
+}
+
+impl FromStr for BilingualLayout {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "stacked" | "lignes" => Ok(BilingualLayout::Stacked),
+            "columns" | "colonnes" => Ok(BilingualLayout::Columns),
+            _ => anyhow::bail!("mise en page bilingue inconnue: '{s}' (stacked|columns)"),
+        }
+    }
+}
+
+/// Découpage du document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocumentLayout {
+    /// Un seul bloc, signé en bas.
+    #[default]
+    Full,
+    /// Partie information conservée par la famille, puis coupon-réponse détaché le long d'un
+    /// pointillé (rappel enfant / classe / date, cases d'accord, signatures). Le coupon est
+    /// rédigé dans la langue principale.
+    Coupon,
+}
+
+impl FromStr for DocumentLayout {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "full" | "complet" => Ok(DocumentLayout::Full),
+            "coupon" => Ok(DocumentLayout::Coupon),
+            _ => anyhow::bail!("mise en page inconnue: '{s}' (full|coupon)"),
+        }
+    }
+}
+
+/// Placement des documents sur la feuille imprimée.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Imposition {
+    /// Une page par feuille, au format de page.
+    #[default]
+    None,
+    /// Deux demi-pages A5 par feuille A4 paysage, avec traits de coupe : le même document
+    /// en double, ou deux documents différents côte à côte.
+    TwoUp,
+}
+
+impl FromStr for Imposition {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "none" | "aucune" => Ok(Imposition::None),
+            "two-up" | "2up" | "2-up" | "a5" => Ok(Imposition::TwoUp),
+            _ => anyhow::bail!("imposition inconnue: '{s}' (none|two-up)"),
+        }
+    }
+}
+
+/// Moteur de rendu PDF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PdfBackend {
+    /// Typst s'il est installé, puis genpdf, puis les polices standard (`Builtin`).
+    #[default]
+    Auto,
+    /// Binaire `typst` local (voir `typst::find_typst`).
+    Typst,
+    /// genpdf intégré (polices TTF dans `./fonts`).
+    Genpdf,
+    /// pdf-writer avec les polices standard 14 : aucune police requise.
+    Builtin,
+}
+
+impl FromStr for PdfBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(PdfBackend::Auto),
+            "typst" => Ok(PdfBackend::Typst),
+            "genpdf" => Ok(PdfBackend::Genpdf),
+            "builtin" | "standard" => Ok(PdfBackend::Builtin),
+            _ => anyhow::bail!("moteur PDF inconnu: '{s}' (auto|typst|genpdf|builtin)"),
+        }
+    }
+}
+
+/// PDF de `form` en mémoire avec `backend`, et le moteur effectivement utilisé : en `Auto`,
+/// chaque moteur absent ou en échec (Typst non installé, polices introuvables) cède la place
+/// au suivant, jusqu'aux polices standard.
+fn render_pdf_bytes_with(
+    backend: PdfBackend,
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+) -> Result<(Vec<u8>, PdfBackend)> {
+    if opts.imposition == Imposition::TwoUp {
+        let bytes = render_pdf_imposed(backend, std::slice::from_ref(form), opts)?;
+        return Ok((bytes, PdfBackend::Builtin));
+    }
+    let bytes = match backend {
+        PdfBackend::Typst => typst::render_pdf_bytes(form, opts)?,
+        PdfBackend::Genpdf => pdf::render_pdf_bytes(form, opts)?,
+        PdfBackend::Builtin => builtin::render_pdf_bytes(form, opts)?,
+        PdfBackend::Auto => {
+            if typst::find_typst().is_some() {
+                match typst::render_pdf_bytes(form, opts) {
+                    Ok(bytes) => return Ok((bytes, PdfBackend::Typst)),
+                    Err(e) => warn!("rendu Typst en échec, repli sur genpdf: {e:#}"),
+                }
+            }
+            match pdf::render_pdf_bytes(form, opts) {
+                Ok(bytes) => return Ok((bytes, PdfBackend::Genpdf)),
+                Err(e) => warn!("rendu genpdf en échec, repli sur les polices standard: {e:#}"),
+            }
+            return Ok((builtin::render_pdf_bytes(form, opts)?, PdfBackend::Builtin));
+        }
+    };
+    Ok((bytes, backend))
+}
+
+/// Planches A4 paysage de `forms`, deux poses A5 par feuille (voir `Imposition::TwoUp`) ; un
+/// formulaire seul est imprimé en double. Seul le moteur `builtin` sait imposer : `Auto` s'y
+/// range d'office.
+pub fn render_pdf_imposed(
+    backend: PdfBackend,
+    forms: &[AutorisationForm],
+    opts: &RenderOptions,
+) -> Result<Vec<u8>> {
+    match backend {
+        PdfBackend::Auto | PdfBackend::Builtin => builtin::render_two_up_bytes(forms, opts),
+        PdfBackend::Typst | PdfBackend::Genpdf => {
+            anyhow::bail!("l'imposition deux poses n'est disponible qu'avec --backend builtin")
+        }
+    }
+}
+
+/// PDF de `form` en mémoire (voir `render_pdf_with` pour le choix du moteur).
+pub fn render_pdf_to_vec(
+    backend: PdfBackend,
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+) -> Result<Vec<u8>> {
+    render_pdf_bytes_with(backend, form, opts).map(|(bytes, _)| bytes)
+}
+
+/// Écrit le PDF de `form` dans `w` et renvoie le moteur utilisé. Le document est produit en
+/// entier avant la première écriture : un repli de `Auto` ne laisse pas de PDF tronqué.
+pub fn render_pdf_to_writer(
+    backend: PdfBackend,
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+    mut w: impl Write,
+) -> Result<PdfBackend> {
+    let (bytes, used) = render_pdf_bytes_with(backend, form, opts)?;
+    w.write_all(&bytes)?;
+    w.flush()?;
+    Ok(used)
+}
+
+/// Rend `form` en PDF avec `backend`, écrit atomiquement dans `out`, et renvoie le moteur
+/// effectivement utilisé : en `Auto`, Typst s'il est installé, puis genpdf, puis les polices
+/// standard.
+pub fn render_pdf_with(
+    backend: PdfBackend,
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+    out: &Path,
+) -> Result<PdfBackend> {
+    let (bytes, used) = render_pdf_bytes_with(backend, form, opts)?;
+    write_atomic(out, &bytes).with_context(|| format!("écriture de {}", out.display()))?;
+    Ok(used)
+}
+
+/// Moteur `pdf` du registre, selon `backend`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PdfRenderer {
+    pub backend: PdfBackend,
+}
+
+impl Renderer for PdfRenderer {
+    fn id(&self) -> &'static str {
+        "pdf"
+    }
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        let used = render_pdf_to_writer(self.backend, ctx.form, ctx.options, w)?;
+        tracing::debug!("PDF rendu avec {used:?}");
+        Ok(())
+    }
+}
+
+/// Lignes de l'en-tête sous le nom de l'école : académie, adresse, puis téléphone et
+/// courriel sur une même ligne.
+pub(crate) fn letterhead_lines(school: &School, loc: Locale) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    lines.extend(school.academy.iter().map(|a| a.trim().to_string()));
+    if let Some(address) = &school.address {
+        lines.extend(address.lines().map(|l| l.trim().to_string()));
+    }
+    let contacts: Vec<String> = [
+        school
+            .phone
+            .as_ref()
+            .map(|p| format!("{} {p}", label(Msg::Phone, loc))),
+        school
+            .email
+            .as_ref()
+            .map(|e| format!("{} {}", label(Msg::Email, loc), e.trim())),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !contacts.is_empty() {
+        lines.push(contacts.join(" · "));
+    }
+    lines.retain(|l| !l.is_empty());
+    lines
+}
+
+/// Champs "libellé : valeur" du corps du document, valeurs localisées pour `loc`.
+pub(crate) fn fields(form: &AutorisationForm, loc: Locale) -> Vec<(Msg, String)> {
+    // un seul enfant : nom puis ligne "Classe" ; fratrie : une ligne par enfant avec sa classe
+    let single = form.enfants.len() == 1;
+    let mut v: Vec<(Msg, String)> = form
+        .enfants
+        .iter()
+        .map(|e| {
+            let name = e.nom_complet();
+            match &e.classe {
+                Some(c) if !single => (Msg::Child, format!("{name} ({c})")),
+                _ => (Msg::Child, name),
+            }
+        })
+        .collect();
+    v.extend([
+        (Msg::Date, format_date_long(form.date, loc)),
+        (Msg::Place, form.lieu.to_string()),
+    ]);
+    if let Some(plage) = form
+        .plage_horaire
+        .as_ref()
+        .and_then(|p| format_plage(p, loc))
+    {
+        v.push((Msg::Times, plage));
+    }
+    if let [Enfant {
+        classe: Some(classe),
+        ..
+    }] = form.enfants.as_slice()
+    {
+        v.push((Msg::Class, classe.clone()));
+    }
+    for resp in &form.responsables {
+        v.push((Msg::Guardian, guardian_name(resp, loc)));
+        if let Some(t) = &resp.telephone {
+            v.push((Msg::Phone, t.to_string()));
+        }
+        if let Some(e) = &resp.email {
+            v.push((Msg::Email, e.clone()));
+        }
+        if let Some(a) = &resp.adresse {
+            v.push((Msg::Address, a.clone()));
+        }
+    }
+    for contact in &form.contacts_urgence {
+        let name = match &contact.lien {
+            Some(l) => format!("{} ({l})", contact.nom),
+            None => contact.nom.to_string(),
+        };
+        v.push((Msg::Emergency, format!("{name} — {}", contact.telephone)));
+    }
+    if let Some(m) = &form.motif {
+        v.push((Msg::Reason, m.clone()));
+    }
+    v
+}
+
+/// "Mme Dupont (mère)" : nom et lien traduit.
+fn guardian_name(resp: &Responsable, loc: Locale) -> String {
+    match resp.lien {
+        Some(l) => format!("{} ({})", resp.nom, lien(l, loc)),
+        None => resp.nom.to_string(),
+    }
+}
+
+/// Rappel en tête du coupon-réponse : enfant(s), date et classe.
+pub(crate) fn coupon_fields(form: &AutorisationForm, loc: Locale) -> Vec<(Msg, String)> {
+    fields(form, loc)
+        .into_iter()
+        .filter(|(msg, _)| matches!(msg, Msg::Child | Msg::Date | Msg::Class))
+        .collect()
+}
+
+/// Cases à cocher du coupon : accord, refus.
+pub(crate) fn consents(loc: Locale) -> [&'static str; 2] {
+    [tr(Msg::Consent, loc), tr(Msg::NoConsent, loc)]
+}
+
+/// Libellés des blocs de signature : un par responsable signataire, ou un bloc générique.
+pub(crate) fn signatures(form: &AutorisationForm, loc: Locale) -> Vec<String> {
+    let signing: Vec<String> = form
+        .responsables
+        .iter()
+        .filter(|r| r.signataire)
+        .map(|r| {
+            with_colon(
+                &format!("{} — {}", tr(Msg::Signature, loc), guardian_name(r, loc)),
+                loc,
+            )
+        })
+        .collect();
+    if signing.is_empty() {
+        vec![label(Msg::Signature, loc)]
+    } else {
+        signing
+    }
+}
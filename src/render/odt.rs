@@ -0,0 +1,172 @@
+// src/render/odt.rs
+#![forbid(unsafe_code)]
+
+//! Export OpenDocument Text (`.odt`), modifiable dans LibreOffice ou OpenOffice.
+use crate::domain::form::AutorisationForm;
+use crate::i18n::catalog::{tr, Msg};
+use crate::i18n::Locale;
+use crate::render::html::escape;
+use crate::render::office::{paragraphs, zip, Align, ParaStyle};
+use crate::render::{Orientation, PageSetup, RenderContext, RenderOptions, Renderer};
+use anyhow::Result;
+use std::io::Write;
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.text";
+
+const NAMESPACES: &str = concat!(
+    "xmlns:office=\"urn:oasis:names:tc:opendocument:xmlns:office:1.0\" ",
+    "xmlns:style=\"urn:oasis:names:tc:opendocument:xmlns:style:1.0\" ",
+    "xmlns:text=\"urn:oasis:names:tc:opendocument:xmlns:text:1.0\" ",
+    "xmlns:fo=\"urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0\" ",
+    "xmlns:dc=\"http://purl.org/dc/elements/1.1/\" ",
+    "xmlns:meta=\"urn:oasis:names:tc:opendocument:xmlns:meta:1.0\" ",
+    "office:version=\"1.3\""
+);
+
+/// Moteur `odt` du registre.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OdtRenderer;
+
+impl Renderer for OdtRenderer {
+    fn id(&self) -> &'static str {
+        "odt"
+    }
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        w.write_all(&render_odt(ctx.form, ctx.options)?)?;
+        Ok(())
+    }
+}
+
+/// Document `.odt` complet, en mémoire.
+pub fn render_odt(form: &AutorisationForm, opts: &RenderOptions) -> Result<Vec<u8>> {
+    let loc = opts.locale(form);
+    let manifest = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<manifest:manifest xmlns:manifest=\"urn:oasis:names:tc:opendocument:xmlns:manifest:1.0\" manifest:version=\"1.3\">\n <manifest:file-entry manifest:full-path=\"/\" manifest:version=\"1.3\" manifest:media-type=\"{MIMETYPE}\"/>\n <manifest:file-entry manifest:full-path=\"content.xml\" manifest:media-type=\"text/xml\"/>\n <manifest:file-entry manifest:full-path=\"styles.xml\" manifest:media-type=\"text/xml\"/>\n <manifest:file-entry manifest:full-path=\"meta.xml\" manifest:media-type=\"text/xml\"/>\n</manifest:manifest>\n"
+    );
+    let meta = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<office:document-meta {NAMESPACES}><office:meta><dc:title>{}</dc:title><dc:language>{}</dc:language><meta:generator>autorisation</meta:generator>{}</office:meta></office:document-meta>\n",
+        escape(tr(Msg::Title, loc)),
+        loc.code(),
+        opts.keywords()
+            .map(|k| format!("<meta:keyword>{}</meta:keyword>", escape(&k)))
+            .unwrap_or_default()
+    );
+    zip(&[
+        ("mimetype", MIMETYPE.as_bytes()),
+        ("META-INF/manifest.xml", manifest.as_bytes()),
+        ("meta.xml", meta.as_bytes()),
+        ("styles.xml", styles_xml(loc, &opts.page).as_bytes()),
+        ("content.xml", content_xml(form, opts).as_bytes()),
+    ])
+}
+
+/// Propriétés de langue (et sens d'écriture) d'un style.
+fn language_props(loc: Locale) -> (String, String) {
+    let writing_mode = if loc.is_rtl() { "rl-tb" } else { "lr-tb" };
+    (
+        format!("style:writing-mode=\"{writing_mode}\""),
+        format!(
+            "fo:language=\"{0}\" fo:country=\"none\" style:language-complex=\"{0}\" style:country-complex=\"none\"",
+            loc.code()
+        ),
+    )
+}
+
+fn styles_xml(loc: Locale, page: &PageSetup) -> String {
+    let (width, height) = page.size_mm();
+    let m = page.margins;
+    let orient = match page.orientation {
+        Orientation::Portrait => "portrait",
+        Orientation::Landscape => "landscape",
+    };
+    let (para_lang, text_lang) = language_props(loc);
+    let mut s = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<office:document-styles {NAMESPACES}>\n<office:styles>\n<style:default-style style:family=\"paragraph\"><style:paragraph-properties {para_lang}/><style:text-properties fo:font-size=\"12pt\" {text_lang}/></style:default-style>\n"
+    );
+    for style in ParaStyle::ALL {
+        let (before, after) = style.spacing_mm();
+        let align = match style.align() {
+            Align::Start => "start",
+            Align::Center => "center",
+            Align::End => "end",
+        };
+        let mut para = format!(
+            "fo:text-align=\"{align}\" fo:margin-top=\"{before}mm\" fo:margin-bottom=\"{after}mm\""
+        );
+        if style.keep_with_next() {
+            para.push_str(" fo:keep-with-next=\"always\"");
+        }
+        let size = style.size_pt();
+        let mut text = format!("fo:font-size=\"{size}pt\" style:font-size-complex=\"{size}pt\"");
+        if style.bold() {
+            text.push_str(" fo:font-weight=\"bold\" style:font-weight-complex=\"bold\"");
+        }
+        if style.italic() {
+            text.push_str(" fo:font-style=\"italic\" style:font-style-complex=\"italic\"");
+        }
+        let outline = style.heading_level().map_or(String::new(), |l| {
+            format!(" style:default-outline-level=\"{l}\"")
+        });
+        s.push_str(&format!(
+            "<style:style style:name=\"{}\" style:display-name=\"{}\" style:family=\"paragraph\"{outline}><style:paragraph-properties {para}/><style:text-properties {text}/></style:style>\n",
+            style.id(),
+            escape(style.display_name())
+        ));
+    }
+    s.push_str(&format!(
+        "</office:styles>\n<office:automatic-styles><style:page-layout style:name=\"pm1\"><style:page-layout-properties fo:page-width=\"{width}mm\" fo:page-height=\"{height}mm\" style:print-orientation=\"{orient}\" fo:margin-top=\"{}mm\" fo:margin-bottom=\"{}mm\" fo:margin-left=\"{}mm\" fo:margin-right=\"{}mm\" {para_lang}/></style:page-layout></office:automatic-styles>\n<office:master-styles><style:master-page style:name=\"Standard\" style:page-layout-name=\"pm1\"/></office:master-styles>\n</office:document-styles>\n",
+        m.top, m.bottom, m.left, m.right
+    ));
+    s
+}
+
+fn content_xml(form: &AutorisationForm, opts: &RenderOptions) -> String {
+    let loc = opts.locale(form);
+    let paras = paragraphs(form, opts);
+
+    // paragraphes dans une autre langue que le document : style automatique dérivé
+    let mut automatic = String::from(
+        "<style:style style:name=\"Libelle\" style:family=\"text\"><style:text-properties fo:font-weight=\"bold\" style:font-weight-complex=\"bold\"/></style:style>\n",
+    );
+    let mut derived: Vec<(ParaStyle, Locale)> = Vec::new();
+    for p in paras.iter().filter(|p| p.locale != loc) {
+        if !derived.contains(&(p.style, p.locale)) {
+            derived.push((p.style, p.locale));
+            let (para_lang, text_lang) = language_props(p.locale);
+            automatic.push_str(&format!(
+                "<style:style style:name=\"{}_{}\" style:family=\"paragraph\" style:parent-style-name=\"{}\"><style:paragraph-properties {para_lang}/><style:text-properties {text_lang}/></style:style>\n",
+                p.style.id(),
+                p.locale.code(),
+                p.style.id()
+            ));
+        }
+    }
+
+    let mut body = String::new();
+    for p in &paras {
+        let style = if p.locale == loc {
+            p.style.id().to_string()
+        } else {
+            format!("{}_{}", p.style.id(), p.locale.code())
+        };
+        let (open, close) = match p.style.heading_level() {
+            Some(l) => (format!("text:h text:outline-level=\"{l}\""), "text:h"),
+            None => ("text:p".to_string(), "text:p"),
+        };
+        let label = p.label.as_deref().map_or(String::new(), |l| {
+            format!(
+                "<text:span text:style-name=\"Libelle\">{}</text:span> ",
+                escape(l)
+            )
+        });
+        body.push_str(&format!(
+            "<{open} text:style-name=\"{style}\">{label}{}</{close}>\n",
+            escape(&p.text)
+        ));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<office:document-content {NAMESPACES}>\n<office:automatic-styles>\n{automatic}</office:automatic-styles>\n<office:body>\n<office:text>\n{body}</office:text>\n</office:body>\n</office:document-content>\n"
+    )
+}
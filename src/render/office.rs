@@ -0,0 +1,304 @@
+// src/render/office.rs
+#![forbid(unsafe_code)]
+
+//! Socle des exports éditables (ODT, DOCX) : le document en paragraphes stylés, que chaque
+//! format traduit en XML, et l'archive zip qui les contient.
+use crate::domain::form::AutorisationForm;
+use crate::i18n::catalog::{label, tr, Msg};
+use crate::i18n::Locale;
+use crate::render::{
+    consents, coupon_fields, fields, letterhead_lines, signatures, DocumentLayout, RenderOptions,
+};
+use anyhow::Result;
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Tirets du pointillé de découpe du coupon.
+const CUT_DASHES: usize = 40;
+
+/// Trait de signature, aligné en fin de ligne.
+const SIGNATURE_LINE: &str = "____________________________";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Align {
+    Start,
+    Center,
+    End,
+}
+
+/// Styles de paragraphe nommés, modifiables ensuite dans le traitement de texte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParaStyle {
+    School,
+    /// Académie, adresse et contacts sous le nom de l'école.
+    SchoolDetails,
+    Title,
+    Subtitle,
+    Caption,
+    Field,
+    Translation,
+    SignedAt,
+    Signature,
+    SignatureLine,
+    CutLine,
+}
+
+impl ParaStyle {
+    pub(crate) const ALL: [ParaStyle; 11] = [
+        ParaStyle::School,
+        ParaStyle::SchoolDetails,
+        ParaStyle::Title,
+        ParaStyle::Subtitle,
+        ParaStyle::Caption,
+        ParaStyle::Field,
+        ParaStyle::Translation,
+        ParaStyle::SignedAt,
+        ParaStyle::Signature,
+        ParaStyle::SignatureLine,
+        ParaStyle::CutLine,
+    ];
+
+    /// Identifiant XML du style.
+    pub(crate) fn id(self) -> &'static str {
+        match self {
+            ParaStyle::School => "AutEcole",
+            ParaStyle::SchoolDetails => "AutCoordonnees",
+            ParaStyle::Title => "AutTitre",
+            ParaStyle::Subtitle => "AutSousTitre",
+            ParaStyle::Caption => "AutMention",
+            ParaStyle::Field => "AutChamp",
+            ParaStyle::Translation => "AutTraduction",
+            ParaStyle::SignedAt => "AutFaitA",
+            ParaStyle::Signature => "AutSignature",
+            ParaStyle::SignatureLine => "AutLigneSignature",
+            ParaStyle::CutLine => "AutDecoupe",
+        }
+    }
+
+    /// Nom affiché dans la liste des styles.
+    pub(crate) fn display_name(self) -> &'static str {
+        match self {
+            ParaStyle::School => "Autorisation - École",
+            ParaStyle::SchoolDetails => "Autorisation - Coordonnées",
+            ParaStyle::Title => "Autorisation - Titre",
+            ParaStyle::Subtitle => "Autorisation - Sous-titre",
+            ParaStyle::Caption => "Autorisation - Mention",
+            ParaStyle::Field => "Autorisation - Champ",
+            ParaStyle::Translation => "Autorisation - Traduction",
+            ParaStyle::SignedAt => "Autorisation - Fait à",
+            ParaStyle::Signature => "Autorisation - Signature",
+            ParaStyle::SignatureLine => "Autorisation - Ligne de signature",
+            ParaStyle::CutLine => "Autorisation - Découpe",
+        }
+    }
+
+    pub(crate) fn size_pt(self) -> u32 {
+        match self {
+            ParaStyle::School => 18,
+            ParaStyle::Title => 22,
+            ParaStyle::Subtitle => 14,
+            ParaStyle::SchoolDetails
+            | ParaStyle::Translation
+            | ParaStyle::Caption
+            | ParaStyle::CutLine => 9,
+            _ => 12,
+        }
+    }
+
+    pub(crate) fn bold(self) -> bool {
+        matches!(
+            self,
+            ParaStyle::School | ParaStyle::Title | ParaStyle::Subtitle
+        )
+    }
+
+    pub(crate) fn italic(self) -> bool {
+        matches!(self, ParaStyle::Translation | ParaStyle::Caption)
+    }
+
+    pub(crate) fn align(self) -> Align {
+        match self {
+            ParaStyle::School
+            | ParaStyle::SchoolDetails
+            | ParaStyle::Title
+            | ParaStyle::Caption
+            | ParaStyle::CutLine => Align::Center,
+            ParaStyle::SignatureLine => Align::End,
+            _ => Align::Start,
+        }
+    }
+
+    /// Espacement avant / après, en mm.
+    pub(crate) fn spacing_mm(self) -> (f64, f64) {
+        match self {
+            ParaStyle::School => (0.0, 3.0),
+            ParaStyle::SchoolDetails => (0.0, 1.0),
+            ParaStyle::Title => (0.0, 8.0),
+            ParaStyle::Subtitle => (4.0, 3.0),
+            ParaStyle::Caption => (0.0, 4.0),
+            ParaStyle::CutLine => (15.0, 1.0),
+            ParaStyle::SignedAt => (10.0, 2.0),
+            ParaStyle::Signature => (6.0, 1.0),
+            ParaStyle::SignatureLine => (10.0, 2.0),
+            ParaStyle::Field | ParaStyle::Translation => (0.0, 2.0),
+        }
+    }
+
+    /// Le bloc de signature ne se coupe pas entre deux pages.
+    pub(crate) fn keep_with_next(self) -> bool {
+        matches!(
+            self,
+            ParaStyle::Signature | ParaStyle::CutLine | ParaStyle::Subtitle
+        )
+    }
+
+    /// Niveau de titre (plan du document).
+    pub(crate) fn heading_level(self) -> Option<u8> {
+        match self {
+            ParaStyle::Title => Some(1),
+            ParaStyle::Subtitle => Some(2),
+            _ => None,
+        }
+    }
+}
+
+/// Paragraphe : libellé en gras facultatif, puis le texte.
+#[derive(Debug, Clone)]
+pub(crate) struct Paragraph {
+    pub style: ParaStyle,
+    pub locale: Locale,
+    pub label: Option<String>,
+    pub text: String,
+}
+
+/// Contenu du document, dans l'ordre du PDF. En bilingue, chaque paragraphe est suivi de sa
+/// traduction (les colonnes deviennent des lignes, plus simples à retoucher). En mise en page
+/// coupon, les signatures passent dans le coupon-réponse.
+pub(crate) fn paragraphs(form: &AutorisationForm, opts: &RenderOptions) -> Vec<Paragraph> {
+    let loc = opts.locale(form);
+    let second = opts.bilingual.map(|b| b.secondary);
+    let mut v = Vec::new();
+    let mut push = |style, locale, label: Option<String>, text: String| {
+        v.push(Paragraph {
+            style,
+            locale,
+            label,
+            text,
+        })
+    };
+
+    if let Some(school) = opts.letterhead() {
+        push(ParaStyle::School, loc, None, school.name.clone());
+        for line in letterhead_lines(school, loc) {
+            push(ParaStyle::SchoolDetails, loc, None, line);
+        }
+    }
+    push(ParaStyle::Title, loc, None, tr(Msg::Title, loc).to_string());
+    if let Some(l) = second {
+        push(
+            ParaStyle::Translation,
+            l,
+            None,
+            tr(Msg::Title, l).to_string(),
+        );
+    }
+    let coupon = opts.layout == DocumentLayout::Coupon;
+    if coupon {
+        push(
+            ParaStyle::Caption,
+            loc,
+            None,
+            tr(Msg::KeepPart, loc).to_string(),
+        );
+    }
+
+    let translated = second.map(|l| fields(form, l));
+    for (i, (msg, value)) in fields(form, loc).into_iter().enumerate() {
+        push(ParaStyle::Field, loc, Some(label(msg, loc)), value);
+        if let (Some(l), Some((msg2, value2))) =
+            (second, translated.as_ref().and_then(|t| t.get(i)))
+        {
+            push(
+                ParaStyle::Translation,
+                l,
+                Some(label(*msg2, l)),
+                value2.clone(),
+            );
+        }
+    }
+
+    if coupon {
+        // coupon-réponse, dans la langue principale
+        push(
+            ParaStyle::CutLine,
+            loc,
+            None,
+            format!("✂ {}", "- ".repeat(CUT_DASHES)),
+        );
+        push(
+            ParaStyle::Caption,
+            loc,
+            None,
+            tr(Msg::CutHere, loc).to_string(),
+        );
+        push(
+            ParaStyle::Subtitle,
+            loc,
+            None,
+            tr(Msg::ReturnSlip, loc).to_string(),
+        );
+        for (msg, value) in coupon_fields(form, loc) {
+            push(ParaStyle::Field, loc, Some(label(msg, loc)), value);
+        }
+        for consent in consents(loc) {
+            push(ParaStyle::Field, loc, Some("☐".into()), consent.to_string());
+        }
+    }
+    let second = second.filter(|_| !coupon);
+    push(ParaStyle::SignedAt, loc, None, signed_at_on(loc));
+    if let Some(l) = second {
+        push(ParaStyle::Translation, l, None, signed_at_on(l));
+    }
+    let translated = second.map(|l| signatures(form, l));
+    for (i, signature) in signatures(form, loc).into_iter().enumerate() {
+        push(ParaStyle::Signature, loc, None, signature);
+        if let (Some(l), Some(sig2)) = (second, translated.as_ref().and_then(|t| t.get(i))) {
+            push(ParaStyle::Translation, l, None, sig2.clone());
+        }
+        push(
+            ParaStyle::SignatureLine,
+            loc,
+            None,
+            SIGNATURE_LINE.to_string(),
+        );
+    }
+    v
+}
+
+fn signed_at_on(loc: Locale) -> String {
+    format!(
+        "{} _______________________, {} _______________________",
+        tr(Msg::SignedAt, loc),
+        tr(Msg::SignedOn, loc)
+    )
+}
+
+/// Archive zip des `entries` (chemin, contenu), dans l'ordre ; `mimetype` est stocké sans
+/// compression, comme l'exige OpenDocument.
+pub(crate) fn zip(entries: &[(&str, &[u8])]) -> Result<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (path, content) in entries {
+        let method = if *path == "mimetype" {
+            CompressionMethod::Stored
+        } else {
+            CompressionMethod::Deflated
+        };
+        zip.start_file(
+            *path,
+            SimpleFileOptions::default().compression_method(method),
+        )?;
+        zip.write_all(content)?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
@@ -0,0 +1,308 @@
+// src/render/page.rs
+#![forbid(unsafe_code)]
+
+//! Mise en page physique : format du papier, orientation et marges, communs à tous les
+//! formats de sortie. Chaque réglage vient, par ordre de priorité, de la ligne de commande,
+//! des variables `AUT_PAGE_SIZE` / `AUT_ORIENTATION` / `AUT_MARGINS`, du fichier de
+//! configuration, puis des valeurs par défaut (A4 portrait).
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Format du papier, en portrait.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum PaperSize {
+    #[default]
+    A4,
+    A5,
+    Letter,
+    Legal,
+    /// Format libre, en mm (largeur × hauteur).
+    Custom {
+        width_mm: f64,
+        height_mm: f64,
+    },
+}
+
+impl PaperSize {
+    /// (largeur, hauteur) en mm, en portrait.
+    pub fn size_mm(self) -> (f64, f64) {
+        match self {
+            PaperSize::A4 => (210.0, 297.0),
+            PaperSize::A5 => (148.0, 210.0),
+            PaperSize::Letter => (215.9, 279.4),
+            PaperSize::Legal => (215.9, 355.6),
+            PaperSize::Custom {
+                width_mm,
+                height_mm,
+            } => (width_mm, height_mm),
+        }
+    }
+}
+
+impl FromStr for PaperSize {
+    type Err = anyhow::Error;
+
+    /// `a4`, `a5`, `letter`, `legal`, ou un format libre `210x297` (mm, suffixe `mm` facultatif).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_ascii_lowercase();
+        match lower.as_str() {
+            "a4" => return Ok(PaperSize::A4),
+            "a5" => return Ok(PaperSize::A5),
+            "letter" | "usletter" | "us_letter" => return Ok(PaperSize::Letter),
+            "legal" | "uslegal" | "us_legal" => return Ok(PaperSize::Legal),
+            _ => {}
+        }
+        let custom = lower
+            .strip_suffix("mm")
+            .unwrap_or(&lower)
+            .split_once(['x', '×'])
+            .and_then(|(w, h)| Some((w.trim().parse::<f64>().ok()?, h.trim().parse::<f64>().ok()?)))
+            .filter(|&(w, h)| (50.0..=1000.0).contains(&w) && (50.0..=1000.0).contains(&h));
+        match custom {
+            Some((width_mm, height_mm)) => Ok(PaperSize::Custom {
+                width_mm,
+                height_mm,
+            }),
+            None => anyhow::bail!(
+                "format de papier inconnu: '{s}' (a4|a5|letter|legal|<largeur>x<hauteur>mm, 50 à 1000 mm)"
+            ),
+        }
+    }
+}
+
+impl TryFrom<String> for PaperSize {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<PaperSize> for String {
+    fn from(p: PaperSize) -> String {
+        p.to_string()
+    }
+}
+
+impl fmt::Display for PaperSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaperSize::A4 => f.write_str("a4"),
+            PaperSize::A5 => f.write_str("a5"),
+            PaperSize::Letter => f.write_str("letter"),
+            PaperSize::Legal => f.write_str("legal"),
+            PaperSize::Custom {
+                width_mm,
+                height_mm,
+            } => write!(f, "{width_mm}x{height_mm}mm"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Orientation {
+    #[default]
+    Portrait,
+    /// Format pivoté : largeur et hauteur échangées.
+    Landscape,
+}
+
+impl FromStr for Orientation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "portrait" => Ok(Orientation::Portrait),
+            "landscape" | "paysage" => Ok(Orientation::Landscape),
+            _ => anyhow::bail!("orientation inconnue: '{s}' (portrait|landscape)"),
+        }
+    }
+}
+
+impl TryFrom<String> for Orientation {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Orientation> for String {
+    fn from(o: Orientation) -> String {
+        match o {
+            Orientation::Portrait => "portrait".into(),
+            Orientation::Landscape => "landscape".into(),
+        }
+    }
+}
+
+/// Marges de la page, en mm.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Margins {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl Default for Margins {
+    fn default() -> Self {
+        Margins {
+            top: 18.0,
+            right: 20.0,
+            bottom: 18.0,
+            left: 20.0,
+        }
+    }
+}
+
+impl FromStr for Margins {
+    type Err = anyhow::Error;
+
+    /// Une à quatre valeurs en mm séparées par des virgules, dans l'ordre CSS : `15` (partout),
+    /// `18,20` (haut/bas, gauche/droite), `10,20,30` (haut, côtés, bas) ou `18,20,18,25`
+    /// (haut, droite, bas, gauche).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|v| {
+                let v = v.trim();
+                v.strip_suffix("mm")
+                    .unwrap_or(v)
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|m| (0.0..=100.0).contains(m))
+                    .with_context(|| format!("marge invalide: '{v}' (mm, de 0 à 100)"))
+            })
+            .collect::<Result<Vec<f64>>>()?;
+        let (top, right, bottom, left) = match values[..] {
+            [all] => (all, all, all, all),
+            [v, h] => (v, h, v, h),
+            [t, h, b] => (t, h, b, h),
+            [t, r, b, l] => (t, r, b, l),
+            _ => anyhow::bail!("marges: une à quatre valeurs attendues, '{s}'"),
+        };
+        Ok(Margins {
+            top,
+            right,
+            bottom,
+            left,
+        })
+    }
+}
+
+impl TryFrom<String> for Margins {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Margins> for String {
+    fn from(m: Margins) -> String {
+        m.to_string()
+    }
+}
+
+impl fmt::Display for Margins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.top, self.right, self.bottom, self.left
+        )
+    }
+}
+
+/// Mise en page effective d'un document.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PageSetup {
+    pub paper: PaperSize,
+    pub orientation: Orientation,
+    pub margins: Margins,
+}
+
+impl PageSetup {
+    /// (largeur, hauteur) de la page en mm, orientation appliquée.
+    pub fn size_mm(&self) -> (f64, f64) {
+        let (w, h) = self.paper.size_mm();
+        match self.orientation {
+            Orientation::Portrait => (w, h),
+            Orientation::Landscape => (h, w),
+        }
+    }
+
+    /// Largeur de la colonne de texte, en mm.
+    pub fn content_width_mm(&self) -> f64 {
+        self.size_mm().0 - self.margins.left - self.margins.right
+    }
+
+    /// Refuse des marges qui ne laissent pas de place au texte.
+    pub fn validate(&self) -> Result<()> {
+        let (w, h) = self.size_mm();
+        let m = &self.margins;
+        if m.left + m.right > w - 40.0 || m.top + m.bottom > h - 40.0 {
+            anyhow::bail!(
+                "marges trop grandes pour une page de {w} × {h} mm (il faut au moins 40 mm de texte)"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Réglages de page partiels d'une source (ligne de commande, environnement, fichier) ; les
+/// sources se complètent avec `or`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PageConfig {
+    pub paper: Option<PaperSize>,
+    pub orientation: Option<Orientation>,
+    pub margins: Option<Margins>,
+}
+
+impl PageConfig {
+    /// `AUT_PAGE_SIZE`, `AUT_ORIENTATION` et `AUT_MARGINS` ; les variables vides sont ignorées.
+    pub fn from_env() -> Result<Self> {
+        fn var<T: FromStr<Err = anyhow::Error>>(name: &str) -> Result<Option<T>> {
+            match std::env::var(name) {
+                Ok(v) if !v.trim().is_empty() => v
+                    .parse()
+                    .map(Some)
+                    .with_context(|| format!("variable {name}")),
+                _ => Ok(None),
+            }
+        }
+        Ok(PageConfig {
+            paper: var("AUT_PAGE_SIZE")?,
+            orientation: var("AUT_ORIENTATION")?,
+            margins: var("AUT_MARGINS")?,
+        })
+    }
+
+    /// Complète les réglages absents avec ceux de `fallback`.
+    pub fn or(self, fallback: PageConfig) -> PageConfig {
+        PageConfig {
+            paper: self.paper.or(fallback.paper),
+            orientation: self.orientation.or(fallback.orientation),
+            margins: self.margins.or(fallback.margins),
+        }
+    }
+
+    /// Mise en page finale, valeurs par défaut pour le reste.
+    pub fn resolve(self) -> Result<PageSetup> {
+        let page = PageSetup {
+            paper: self.paper.unwrap_or_default(),
+            orientation: self.orientation.unwrap_or_default(),
+            margins: self.margins.unwrap_or_default(),
+        };
+        page.validate()?;
+        Ok(page)
+    }
+}
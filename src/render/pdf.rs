@@ -1,44 +1,250 @@
 // src/render/pdf.rs
 #![forbid(unsafe_code)]
 
-use crate::domain::form::AutorisationForm;
-use crate::domain::format::human_date_fr;
+use crate::domain::form::{AutorisationForm, Enfant};
+use crate::domain::school::School;
+use crate::i18n::catalog::{label, tr, Msg};
+use crate::i18n::date::{format_date_long, format_plage};
+use crate::i18n::Locale;
+use crate::infra::fs::write_atomic;
+use crate::render::logo::{Logo, LOGO_HEIGHT_MM};
+use crate::render::theme::{Color, HeadingAlign, Theme};
+use crate::render::{
+    consents, coupon_fields, fields, letterhead_lines, signatures, Bilingual, BilingualLayout,
+    DocumentLayout, RenderOptions,
+};
 use anyhow::{Context, Result};
 use genpdf::{
-    elements, fonts, style, Alignment, Document, Element, Margins, PaperSize, SimplePageDecorator,
+    elements, fonts, render, style, Alignment, Document, Element, Margins, PageDecorator, Position,
 };
 use std::env;
 use std::path::Path;
 
-/// Page margins (defaults) — gauche, droite, haut, bas (modifiable)
-const MARGIN_LEFT_MM: f64 = 20.0;
-const MARGIN_RIGHT_MM: f64 = 20.0;
-const MARGIN_TOP_MM: f64 = 18.0;
-const MARGIN_BOTTOM_MM: f64 = 18.0;
-
-/// Typography (points)
-const BASELINE_PT: u8 = 8; // baseline grid (used for spacing math)
-const H1_PT: u8 = 32;
-const H2_PT: u8 = 24;
-const H3_PT: u8 = 18;
-const BODY_PT: u8 = 16;
-const CAPTION_PT: u8 = 12;
-
 /// Grid
 const GRID_COLS: usize = 12;
 
-pub fn render_pdf(form: &AutorisationForm, school_name: Option<&str>, out: &Path) -> Result<()> {
-    // page size env override (A4|LETTER)
-    let page_size_env = env::var("AUT_PAGE_SIZE").unwrap_or_else(|_| "A4".into());
-    let paper = match page_size_env.to_uppercase().as_str() {
-        "LETTER" | "USLETTER" | "US_LETTER" => PaperSize::Letter,
-        _ => PaperSize::A4,
-    };
+/// Tirets du pointillé de découpe du coupon (tient sur une ligne de la colonne de texte)
+const CUT_DASHES: usize = 30;
+
+/// Guardian / emergency lines, listed after motif and classe in the single-language layout
+const GUARDIAN_MSGS: &[Msg] = &[
+    Msg::Guardian,
+    Msg::Phone,
+    Msg::Email,
+    Msg::Address,
+    Msg::Emergency,
+];
+
+/// Styles genpdf du thème : tailles en points entiers, couleurs, alignement des titres.
+#[derive(Clone, Copy)]
+struct Styles {
+    h1: style::Style,
+    h2: style::Style,
+    h3: style::Style,
+    body: style::Style,
+    caption: style::Style,
+    heading_align: Alignment,
+    /// Unité d'espacement vertical, en lignes de texte courant.
+    baseline: f64,
+}
+
+impl Styles {
+    fn new(theme: &Theme) -> Self {
+        let size = |pt: f32| pt.round().clamp(1.0, 255.0) as u8;
+        let color = |c: Color| style::Color::Rgb(c.r, c.g, c.b);
+        let text = style::Style::new().with_color(color(theme.text_color));
+        let heading = style::Style::new()
+            .with_color(color(theme.heading_color))
+            .bold();
+        Styles {
+            h1: heading.with_font_size(size(theme.h1_pt)),
+            h2: heading.with_font_size(size(theme.h2_pt)),
+            h3: heading.with_font_size(size(theme.h3_pt)),
+            body: text.with_font_size(size(theme.body_pt)),
+            caption: text.with_font_size(size(theme.caption_pt)),
+            heading_align: match theme.heading_align {
+                HeadingAlign::Left => Alignment::Left,
+                HeadingAlign::Center => Alignment::Center,
+                HeadingAlign::Right => Alignment::Right,
+            },
+            baseline: (theme.baseline_pt / theme.body_pt) as f64,
+        }
+    }
+
+    /// Espace vertical de `mult` unités de la grille de base.
+    fn gap(&self, mult: f64) -> elements::Break {
+        elements::Break::new(self.baseline * mult)
+    }
+}
+
+/// En-tête de l'école : logo à gauche s'il y en a un, nom et coordonnées.
+struct Letterhead {
+    name: String,
+    lines: Vec<String>,
+    /// Logo et poids des deux colonnes (logo, texte) de la grille.
+    logo: Option<(elements::Image, [usize; 2])>,
+    every_page: bool,
+}
+
+impl Letterhead {
+    fn new(school: &School, loc: Locale, content_width_mm: f64) -> Result<Self> {
+        let logo = match &school.logo {
+            Some(path) => {
+                let logo = Logo::load(path)?;
+                let width = logo.width_mm(LOGO_HEIGHT_MM);
+                // genpdf dimensionne les images par leur résolution
+                let dpi = logo.height as f64 * 25.4 / LOGO_HEIGHT_MM;
+                let image = elements::Image::from_dynamic_image(logo.flattened())
+                    .map_err(|e| anyhow::anyhow!("logo {}: {e}", path.display()))?
+                    .with_dpi(dpi);
+                let logo_col = (width + 5.0).round() as usize;
+                let text_col = (content_width_mm.round() as usize).saturating_sub(logo_col);
+                Some((image, [logo_col, text_col.max(1)]))
+            }
+            None => None,
+        };
+        Ok(Letterhead {
+            name: school.name.clone(),
+            lines: letterhead_lines(school, loc),
+            logo,
+            every_page: school.every_page,
+        })
+    }
+
+    fn element(&self, st: &Styles) -> elements::LinearLayout {
+        let align = if self.logo.is_some() {
+            Alignment::Left
+        } else {
+            st.heading_align
+        };
+        let mut text = elements::LinearLayout::vertical();
+        text.push(
+            elements::Paragraph::new(self.name.clone())
+                .aligned(align)
+                .styled(st.h2),
+        );
+        for line in &self.lines {
+            text.push(
+                elements::Paragraph::new(line.clone())
+                    .aligned(align)
+                    .styled(st.caption),
+            );
+        }
+        let mut header = elements::LinearLayout::vertical();
+        match &self.logo {
+            Some((image, weights)) => {
+                let mut table = elements::TableLayout::new(weights.to_vec());
+                table
+                    .row()
+                    .element(image.clone())
+                    .element(text)
+                    .push()
+                    .expect("table row push");
+                header.push(table);
+            }
+            None => header.push(text),
+        }
+        header.push(st.gap(1.0));
+        header
+    }
+}
+
+/// Décorateur de page : applique les quatre marges de `PageSetup` (le `SimplePageDecorator`
+/// de genpdf n'en connaît qu'une) et place l'en-tête de l'école sur la première page, ou sur
+/// toutes.
+struct MarginDecorator {
+    margins: Margins,
+    letterhead: Option<Letterhead>,
+    styles: Styles,
+    page: usize,
+}
 
-    // Fonts: try primary (env) then fallback to DejaVuSans in ./fonts
+impl PageDecorator for MarginDecorator {
+    fn decorate_page<'a>(
+        &mut self,
+        context: &genpdf::Context,
+        mut area: render::Area<'a>,
+        style: style::Style,
+    ) -> Result<render::Area<'a>, genpdf::error::Error> {
+        self.page += 1;
+        area.add_margins(self.margins);
+        if let Some(letterhead) = &self.letterhead {
+            if self.page == 1 || letterhead.every_page {
+                let result =
+                    letterhead
+                        .element(&self.styles)
+                        .render(context, area.clone(), style)?;
+                area.add_offset(Position::new(0, result.size.height));
+            }
+        }
+        Ok(area)
+    }
+}
+
+/// Rendu PDF via genpdf, écrit atomiquement dans `out`.
+pub fn render_pdf(form: &AutorisationForm, opts: &RenderOptions, out: &Path) -> Result<()> {
+    let bytes = render_pdf_bytes(form, opts)?;
+    write_atomic(out, &bytes).with_context(|| format!("écriture de {}", out.display()))
+}
+
+/// Document PDF complet via genpdf, en mémoire.
+pub fn render_pdf_bytes(form: &AutorisationForm, opts: &RenderOptions) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    build_document(form, opts)?
+        .render(&mut bytes)
+        .context("échec lors du rendu PDF avec genpdf")?;
+    match opts.keywords() {
+        Some(keywords) => with_keywords(bytes, &keywords),
+        None => Ok(bytes),
+    }
+}
+
+/// Ajoute `/Keywords` au dictionnaire Info du PDF (genpdf n'expose que le titre).
+fn with_keywords(pdf: Vec<u8>, keywords: &str) -> Result<Vec<u8>> {
+    let mut doc = lopdf::Document::load_mem(&pdf).context("relecture du PDF genpdf")?;
+    let info = doc
+        .trailer
+        .get(b"Info")
+        .and_then(lopdf::Object::as_reference)
+        .context("PDF genpdf sans dictionnaire Info")?;
+    doc.get_object_mut(info)
+        .and_then(lopdf::Object::as_dict_mut)
+        .context("PDF genpdf : dictionnaire Info illisible")?
+        .set("Keywords", text_string(keywords));
+    let mut out = Vec::with_capacity(pdf.len());
+    doc.save_to(&mut out).context("écriture du PDF genpdf")?;
+    Ok(out)
+}
+
+/// Chaîne de texte PDF : littérale en ASCII, sinon UTF-16BE avec BOM.
+fn text_string(s: &str) -> lopdf::Object {
+    if s.is_ascii() {
+        lopdf::Object::string_literal(s)
+    } else {
+        let bytes = [0xFE, 0xFF]
+            .into_iter()
+            .chain(s.encode_utf16().flat_map(u16::to_be_bytes))
+            .collect();
+        lopdf::Object::String(bytes, lopdf::StringFormat::Hexadecimal)
+    }
+}
+
+/// Document genpdf prêt à rendre.
+///
+/// Les libellés suivent `RenderOptions::locale`. genpdf ne gère ni le shaping ni le sens
+/// d'écriture : l'arabe s'affiche avec les glyphes isolés de la police, de gauche à droite.
+fn build_document(form: &AutorisationForm, opts: &RenderOptions) -> Result<Document> {
+    let loc = opts.locale(form);
+    let paper = opts.page.size_mm();
+
+    // Fonts: theme family, then env, then fallback to DejaVuSans in ./fonts
     let font_name_env = env::var("AUT_FONT_FAMILY").ok();
     let font_candidates = [
-        font_name_env.as_deref().unwrap_or("Inter"),
+        opts.theme
+            .font_family
+            .as_deref()
+            .or(font_name_env.as_deref())
+            .unwrap_or("Inter"),
         "DejaVuSans",
         "LiberationSans",
     ];
@@ -72,74 +278,65 @@ pub fn render_pdf(form: &AutorisationForm, school_name: Option<&str>, out: &Path
     // Document
     let mut doc = Document::new(font_family);
     doc.set_paper_size(paper);
-    doc.set_title("Autorisation de sortie");
-
-    // Base font size (document default) = BODY_PT
-    doc.set_font_size(BODY_PT);
-
-    // Page decorator (uniform margins via SimplePageDecorator)
-    let mut decorator = SimplePageDecorator::new();
-    // Use the minimum margin as SimplePageDecorator::set_margins expects a single value in points
-    let min_margin_mm = MARGIN_LEFT_MM
-        .min(MARGIN_RIGHT_MM)
-        .min(MARGIN_TOP_MM)
-        .min(MARGIN_BOTTOM_MM);
-    let min_margin_pt = ((min_margin_mm * 72.0) / 25.4).round() as u32;
-    decorator.set_margins(min_margin_pt);
-
-    // Header: only show school name prominently on page 1
-    let school_owned = school_name.map(|s| s.to_string());
-    decorator.set_header(move |page| {
-        let mut layout = elements::LinearLayout::vertical();
-        if page == 1 {
-            if let Some(ref s) = school_owned {
-                if !s.is_empty() {
-                    layout.push(
-                        elements::Paragraph::new(s.clone())
-                            .aligned(Alignment::Center)
-                            .styled(style::Style::new().with_font_size(H2_PT).bold()),
-                    );
-                    layout.push(elements::Break::new(
-                        (BASELINE_PT as f64 * 0.5) / BODY_PT as f64,
-                    ));
-                }
-            }
-        }
-        layout.styled(style::Style::new().with_font_size((BODY_PT - 2).max(8)))
+    doc.set_title(tr(Msg::Title, loc));
+
+    // Base font size and line spacing (document defaults) from the theme
+    let st = Styles::new(&opts.theme);
+    doc.set_font_size(st.body.font_size());
+    // genpdf spaces lines by the font's own line height (about 1.2 em)
+    doc.set_line_spacing(opts.theme.line_height as f64 / 1.2);
+
+    // Page decorator: per-side margins, school letterhead on page 1 (or every page)
+    let m = opts.page.margins;
+    let letterhead = opts
+        .letterhead()
+        .map(|school| Letterhead::new(school, loc, opts.page.content_width_mm()))
+        .transpose()?;
+    doc.set_page_decorator(MarginDecorator {
+        margins: Margins::trbl(m.top, m.right, m.bottom, m.left),
+        letterhead,
+        styles: st,
+        page: 0,
     });
-    doc.set_page_decorator(decorator);
 
-    // Styles (Style implements Copy — no need to clone)
-    let h1_style = style::Style::new().with_font_size(H1_PT).bold();
-    let h2_style = style::Style::new().with_font_size(H2_PT).bold();
-    let h3_style = style::Style::new().with_font_size(H3_PT).bold();
-    let body_style = style::Style::new().with_font_size(BODY_PT);
-    let caption_style = style::Style::new().with_font_size(CAPTION_PT);
+    let coupon = opts.layout == DocumentLayout::Coupon;
+    if let Some(bi) = opts.bilingual {
+        push_bilingual(&mut doc, form, loc, bi, !coupon, &st);
+        if coupon {
+            push_coupon(&mut doc, form, loc, &st);
+        }
+        return Ok(doc);
+    }
 
     // Start content
     doc.push(
-        elements::Paragraph::new("Autorisation de sortie")
-            .aligned(Alignment::Center)
-            .styled(h1_style),
+        elements::Paragraph::new(tr(Msg::Title, loc))
+            .aligned(st.heading_align)
+            .styled(st.h1),
     );
-    doc.push(elements::Break::new(
-        (BASELINE_PT as f64 * 2.0) / BODY_PT as f64,
-    ));
+    if coupon {
+        doc.push(
+            elements::Paragraph::new(tr(Msg::KeepPart, loc))
+                .aligned(Alignment::Center)
+                .styled(st.caption.italic()),
+        );
+    }
+    doc.push(st.gap(2.0));
 
     // Build a 12-column table to place meta (grille démonstration)
     let mut table = elements::TableLayout::new(vec![1; GRID_COLS]);
 
-    // Row: child name (first cell), rest empty
-    {
+    // Rows: one per child name (first cell), rest empty
+    let single = form.enfants.len() == 1;
+    for enfant in &form.enfants {
         let mut row = table.row();
-        let enfant = format!(
-            "{} {}",
-            form.enfant.nom,
-            form.enfant.prenom.clone().unwrap_or_default()
-        );
+        let name = match &enfant.classe {
+            Some(c) if !single => format!("{} ({c})", enfant.nom_complet()),
+            _ => enfant.nom_complet(),
+        };
         row.push_element(
-            elements::Paragraph::new(enfant)
-                .styled(h2_style)
+            elements::Paragraph::new(name)
+                .styled(st.h2)
                 .padded(Margins::trbl(0.0, 3.0, 0.0, 0.0)),
         );
         for _ in 1..GRID_COLS {
@@ -150,17 +347,19 @@ pub fn render_pdf(form: &AutorisationForm, school_name: Option<&str>, out: &Path
 
     // Second row: date / lieu positioned to the right columns
     {
-        let date_str = human_date_fr(&form.date);
-        let lieu = form.lieu.clone();
+        let date_str = format_date_long(form.date, loc);
+        let lieu = &form.lieu;
         let mut row = table.row();
         for col in 0..GRID_COLS {
             if col == GRID_COLS - 4 {
                 row.push_element(
-                    elements::Paragraph::new(format!("Date: {date_str}")).styled(body_style),
+                    elements::Paragraph::new(format!("{} {date_str}", label(Msg::Date, loc)))
+                        .styled(st.body),
                 );
             } else if col == GRID_COLS - 2 {
                 row.push_element(
-                    elements::Paragraph::new(format!("Lieu: {lieu}")).styled(body_style),
+                    elements::Paragraph::new(format!("{} {lieu}", label(Msg::Place, loc)))
+                        .styled(st.body),
                 );
             } else {
                 row.push_element(elements::Paragraph::new(""));
@@ -171,59 +370,202 @@ pub fn render_pdf(form: &AutorisationForm, school_name: Option<&str>, out: &Path
 
     // push table (it will occupy full width)
     doc.push(table);
-    doc.push(elements::Break::new(
-        (BASELINE_PT as f64 * 1.0) / BODY_PT as f64,
-    ));
+    doc.push(st.gap(1.0));
+
+    // Horaires (plage horaire), just under date / lieu
+    if let Some(plage) = form
+        .plage_horaire
+        .as_ref()
+        .and_then(|p| format_plage(p, loc))
+    {
+        doc.push(
+            elements::Paragraph::new(format!("{} {plage}", label(Msg::Times, loc))).styled(st.body),
+        );
+        doc.push(st.gap(0.5));
+    }
 
     // Motif, classe, responsable
     if let Some(motif) = &form.motif {
-        doc.push(elements::Paragraph::new("Motif :").styled(h3_style));
-        doc.push(elements::Paragraph::new(motif.clone()).styled(body_style));
-        doc.push(elements::Break::new(
-            (BASELINE_PT as f64 * 0.5) / BODY_PT as f64,
-        ));
-    }
-    if let Some(classe) = &form.classe {
-        doc.push(elements::Paragraph::new(format!("Classe : {classe}")).styled(body_style));
-        doc.push(elements::Break::new(
-            (BASELINE_PT as f64 * 0.5) / BODY_PT as f64,
-        ));
-    }
-    if let Some(resp) = &form.responsable {
+        doc.push(elements::Paragraph::new(label(Msg::Reason, loc)).styled(st.h3));
+        doc.push(elements::Paragraph::new(motif.clone()).styled(st.body));
+        doc.push(st.gap(0.5));
+    }
+    if let [Enfant {
+        classe: Some(classe),
+        ..
+    }] = form.enfants.as_slice()
+    {
         doc.push(
-            elements::Paragraph::new(
-                format!("Responsable légal : {}", resp.nom).replace("{resp}", &resp.nom),
-            )
-            .styled(body_style),
+            elements::Paragraph::new(format!("{} {classe}", label(Msg::Class, loc)))
+                .styled(st.body),
         );
-        if let Some(tel) = &resp.telephone {
-            doc.push(elements::Paragraph::new(format!("Tél : {tel}")).styled(body_style));
-        }
-        doc.push(elements::Break::new(
-            (BASELINE_PT as f64 * 1.0) / BODY_PT as f64,
-        ));
+        doc.push(st.gap(0.5));
+    }
+    for (msg, value) in fields(form, loc)
+        .into_iter()
+        .filter(|(msg, _)| GUARDIAN_MSGS.contains(msg))
+    {
+        doc.push(elements::Paragraph::new(format!("{} {value}", label(msg, loc))).styled(st.body));
     }
+    doc.push(st.gap(1.0));
 
-    // Signature block (anchored to baseline grid)
+    if coupon {
+        push_coupon(&mut doc, form, loc, &st);
+    } else {
+        push_signatures(&mut doc, form, loc, &st);
+    }
+
+    Ok(doc)
+}
+
+/// Bloc de signature (ancré sur la grille de base), un par responsable signataire.
+fn push_signatures(doc: &mut Document, form: &AutorisationForm, loc: Locale, st: &Styles) {
     doc.push(
-        elements::Paragraph::new("Fait à _______________________, le _______________________")
-            .styled(body_style),
+        elements::Paragraph::new(format!(
+            "{} _______________________, {} _______________________",
+            tr(Msg::SignedAt, loc),
+            tr(Msg::SignedOn, loc)
+        ))
+        .styled(st.body),
     );
-    doc.push(elements::Break::new(
-        (BASELINE_PT as f64 * 2.0) / BODY_PT as f64,
-    ));
-    doc.push(elements::Paragraph::new("Signature du responsable légal :").styled(body_style));
-    doc.push(elements::Break::new(
-        (BASELINE_PT as f64 * 2.0) / BODY_PT as f64,
-    ));
+    for signature in signatures(form, loc) {
+        doc.push(st.gap(2.0));
+        doc.push(elements::Paragraph::new(signature).styled(st.body));
+        doc.push(st.gap(2.0));
+        doc.push(
+            elements::Paragraph::new("____________________________")
+                .aligned(Alignment::Right)
+                .styled(st.caption),
+        );
+    }
+}
+
+/// Coupon-réponse : pointillé avec ciseaux, rappel enfant / date / classe, cases d'accord
+/// et signatures.
+fn push_coupon(doc: &mut Document, form: &AutorisationForm, loc: Locale, st: &Styles) {
+    doc.push(st.gap(3.0));
+    doc.push(elements::Paragraph::new(format!("✂ {}", "- ".repeat(CUT_DASHES))).styled(st.caption));
     doc.push(
-        elements::Paragraph::new("____________________________")
-            .aligned(Alignment::Right)
-            .styled(caption_style),
+        elements::Paragraph::new(tr(Msg::CutHere, loc))
+            .aligned(Alignment::Center)
+            .styled(st.caption.italic()),
     );
+    doc.push(st.gap(1.0));
+    doc.push(elements::Paragraph::new(tr(Msg::ReturnSlip, loc)).styled(st.h3));
+    doc.push(st.gap(0.5));
+    for (msg, value) in coupon_fields(form, loc) {
+        doc.push(elements::Paragraph::new(format!("{} {value}", label(msg, loc))).styled(st.body));
+    }
+    doc.push(st.gap(1.0));
+    for consent in consents(loc) {
+        doc.push(elements::Paragraph::new(format!("☐  {consent}")).styled(st.body));
+    }
+    doc.push(st.gap(1.0));
+    push_signatures(doc, form, loc, st);
+}
 
-    // Render
-    doc.render_to_file(out)
-        .context("échec lors du rendu PDF avec genpdf")?;
-    Ok(())
+/// Corps bilingue : deux demi-grilles (colonnes) ou ligne principale + traduction en dessous.
+fn push_bilingual(
+    doc: &mut Document,
+    form: &AutorisationForm,
+    primary: Locale,
+    bi: Bilingual,
+    with_signatures: bool,
+    st: &Styles,
+) {
+    let second = bi.secondary;
+    let subtitle = st.body.with_font_size(st.h3.font_size()).italic();
+    let translation = st.caption.italic();
+    let signed = |loc: Locale| {
+        format!(
+            "{} ______________, {} ______________",
+            tr(Msg::SignedAt, loc),
+            tr(Msg::SignedOn, loc)
+        )
+    };
+
+    doc.push(
+        elements::Paragraph::new(tr(Msg::Title, primary))
+            .aligned(st.heading_align)
+            .styled(st.h1),
+    );
+    doc.push(
+        elements::Paragraph::new(tr(Msg::Title, second))
+            .aligned(st.heading_align)
+            .styled(subtitle),
+    );
+    doc.push(st.gap(2.0));
+
+    let rows = fields(form, primary).into_iter().zip(fields(form, second));
+    match bi.layout {
+        BilingualLayout::Columns => {
+            // the 12-column grid split in two halves: primary | secondary
+            let mut table = elements::TableLayout::new(vec![GRID_COLS / 2; 2]);
+            let mut push_row = |left: String, right: String| {
+                let mut row = table.row();
+                row.push_element(
+                    elements::Paragraph::new(left)
+                        .styled(st.body)
+                        .padded(Margins::trbl(1.0, 3.0, 1.0, 0.0)),
+                );
+                row.push_element(
+                    elements::Paragraph::new(right)
+                        .styled(translation)
+                        .padded(Margins::trbl(1.0, 0.0, 1.0, 3.0)),
+                );
+                row.push().expect("table row push");
+            };
+            for ((msg, value), (_, value2)) in rows {
+                push_row(
+                    format!("{} {value}", label(msg, primary)),
+                    format!("{} {value2}", label(msg, second)),
+                );
+            }
+            if with_signatures {
+                push_row(signed(primary), signed(second));
+                for (sig, sig2) in signatures(form, primary)
+                    .into_iter()
+                    .zip(signatures(form, second))
+                {
+                    push_row(sig, sig2);
+                    push_row(String::new(), String::new());
+                    push_row("____________________________".into(), String::new());
+                }
+            }
+            doc.push(table);
+        }
+        BilingualLayout::Stacked => {
+            for ((msg, value), (_, value2)) in rows {
+                doc.push(
+                    elements::Paragraph::new(format!("{} {value}", label(msg, primary)))
+                        .styled(st.body),
+                );
+                doc.push(
+                    elements::Paragraph::new(format!("{} {value2}", label(msg, second)))
+                        .styled(translation),
+                );
+                doc.push(st.gap(0.5));
+            }
+            if !with_signatures {
+                return;
+            }
+            doc.push(st.gap(1.0));
+            doc.push(elements::Paragraph::new(signed(primary)).styled(st.body));
+            doc.push(elements::Paragraph::new(signed(second)).styled(translation));
+            for (sig, sig2) in signatures(form, primary)
+                .into_iter()
+                .zip(signatures(form, second))
+            {
+                doc.push(st.gap(1.0));
+                doc.push(elements::Paragraph::new(sig).styled(st.body));
+                doc.push(elements::Paragraph::new(sig2).styled(translation));
+                doc.push(st.gap(2.0));
+                doc.push(
+                    elements::Paragraph::new("____________________________")
+                        .aligned(Alignment::Right)
+                        .styled(st.caption),
+                );
+            }
+        }
+    }
 }
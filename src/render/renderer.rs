@@ -0,0 +1,112 @@
+//! Moteurs de rendu interchangeables : un `Renderer` par format de sortie, choisi par son
+//! identifiant (`--format pdf,md`) dans un `Registry` que les crates clientes peuvent
+//! compléter avec leurs propres moteurs.
+use crate::domain::form::AutorisationForm;
+use crate::i18n::Locale;
+use crate::infra::fs::write_atomic;
+use crate::render::RenderOptions;
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::path::Path;
+
+/// Contexte commun à tous les moteurs : formulaire, options (école, langues) et langue
+/// effective du document.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderContext<'a> {
+    pub form: &'a AutorisationForm,
+    pub options: &'a RenderOptions,
+    pub locale: Locale,
+}
+
+impl<'a> RenderContext<'a> {
+    pub fn new(form: &'a AutorisationForm, options: &'a RenderOptions) -> Self {
+        RenderContext {
+            form,
+            options,
+            locale: options.locale(form),
+        }
+    }
+}
+
+pub trait Renderer: Send + Sync {
+    /// Identifiant du format (`pdf`, `md`...), tel qu'accepté par `--format`.
+    fn id(&self) -> &'static str;
+
+    /// Extension du fichier produit, sans point.
+    fn extension(&self) -> &'static str {
+        self.id()
+    }
+
+    /// Écrit le document dans `w` (fichier, sortie standard, tampon...).
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()>;
+
+    /// Document en mémoire.
+    fn render_to_vec(&self, ctx: &RenderContext<'_>) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.render_to_writer(ctx, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Écrit le document atomiquement dans `out`.
+    fn render(&self, ctx: &RenderContext<'_>, out: &Path) -> Result<()> {
+        let bytes = self.render_to_vec(ctx)?;
+        write_atomic(out, &bytes).with_context(|| format!("écriture de {}", out.display()))
+    }
+}
+
+/// Ensemble de moteurs indexés par identifiant ; un enregistrement remplace, à la même
+/// place, le moteur de même identifiant.
+#[derive(Default)]
+pub struct Registry {
+    renderers: Vec<Box<dyn Renderer>>,
+}
+
+impl Registry {
+    /// Registre vide.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moteurs fournis par la crate : `pdf`, `md`, `txt`, `typ` (source Typst), `html`,
+    /// `odt` et `docx`.
+    pub fn with_defaults() -> Self {
+        let mut r = Self::new();
+        r.register(crate::render::PdfRenderer::default());
+        r.register(crate::render::markdown::MarkdownRenderer);
+        r.register(crate::render::text::TextRenderer);
+        r.register(crate::render::typst::TypstSourceRenderer);
+        r.register(crate::render::html::HtmlRenderer);
+        r.register(crate::render::odt::OdtRenderer);
+        r.register(crate::render::docx::DocxRenderer);
+        r
+    }
+
+    pub fn register(&mut self, renderer: impl Renderer + 'static) {
+        match self.renderers.iter().position(|r| r.id() == renderer.id()) {
+            Some(i) => self.renderers[i] = Box::new(renderer),
+            None => self.renderers.push(Box::new(renderer)),
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn Renderer> {
+        self.renderers
+            .iter()
+            .find(|r| r.id().eq_ignore_ascii_case(id.trim()))
+            .map(|r| r.as_ref())
+    }
+
+    /// Identifiants disponibles, dans l'ordre d'enregistrement.
+    pub fn ids(&self) -> Vec<&'static str> {
+        self.renderers.iter().map(|r| r.id()).collect()
+    }
+
+    /// Moteur `id`, ou une erreur listant les formats disponibles.
+    pub fn require(&self, id: &str) -> Result<&dyn Renderer> {
+        self.get(id).ok_or_else(|| {
+            anyhow::anyhow!(
+                "format de sortie inconnu '{id}' (disponibles : {})",
+                self.ids().join(", ")
+            )
+        })
+    }
+}
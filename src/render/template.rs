@@ -0,0 +1,356 @@
+// src/render/template.rs
+#![forbid(unsafe_code)]
+
+//! Gabarits texte (syntaxe Jinja, moteur minijinja) pour les sorties Markdown et texte : les
+//! écoles reformulent le document sans toucher au code. Les gabarits par défaut
+//! (`templates/autorisation.md`, `templates/autorisation.txt`) produisent la sortie standard
+//! et servent de point de départ.
+//!
+//! Variables :
+//! - données du formulaire, comme dans le fichier d'entrée : `enfants` (`nom`, `prenom`,
+//!   `classe`, `nom_complet`), `date` (JJ/MM/AAAA), `lieu`, `responsables` (`nom`, `lien`
+//!   traduit, `telephone`, `email`, `adresse`, `signataire`), `contacts_urgence` (`nom`,
+//!   `lien`, `telephone`), `plage_horaire` (`debut`, `fin` en HH:MM), `horaires` (plage
+//!   localisée), `motif` ;
+//! - `lang`, `lang_name`, `rtl`, `layout` (`full`/`coupon`), `coupon`, `profile`, `school`
+//!   (`name`, `logo`, `lines`) ;
+//! - `t` : libellés traduits sans ponctuation (`t.title`, `t.child`, `t.place`,
+//!   `t.signature`...) ;
+//! - `fields` et `coupon_fields` : lignes du document (`key`, `label` avec deux-points,
+//!   `value`), `signatures`, `consents` ;
+//! - `second` : seconde langue d'un document bilingue (`lang`, `lang_name`, `layout`
+//!   `stacked`/`columns`, `t`, `fields`, `signatures`), sinon vide.
+//!
+//! Filtres : `date` (`long` par défaut, `short`, ou description `time` comme
+//! `"[day].[month].[year]"`), `heure` (HH:MM localisée), `colon` (deux-points selon la langue),
+//! `cell` (échappe `|` dans un tableau Markdown).
+use crate::domain::form::AutorisationForm;
+use crate::i18n::catalog::{lien, tr, with_colon, Msg};
+use crate::i18n::date::{format_date_long, format_plage, format_time};
+use crate::i18n::Locale;
+use crate::render::{
+    consents, coupon_fields, fields, letterhead_lines, signatures, BilingualLayout, DocumentLayout,
+    RenderOptions,
+};
+use anyhow::{Context as _, Result};
+use minijinja::{Environment, Error, ErrorKind, UndefinedBehavior};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Gabarit Markdown par défaut.
+pub const DEFAULT_MARKDOWN: &str = include_str!("templates/autorisation.md");
+
+/// Gabarit texte par défaut.
+pub const DEFAULT_TEXT: &str = include_str!("templates/autorisation.txt");
+
+/// Clés de `t` et de `fields[].key`.
+const LABELS: &[(&str, Msg)] = &[
+    ("title", Msg::Title),
+    ("child", Msg::Child),
+    ("date", Msg::Date),
+    ("place", Msg::Place),
+    ("times", Msg::Times),
+    ("class", Msg::Class),
+    ("guardian", Msg::Guardian),
+    ("phone", Msg::Phone),
+    ("email", Msg::Email),
+    ("address", Msg::Address),
+    ("emergency", Msg::Emergency),
+    ("reason", Msg::Reason),
+    ("signed_at", Msg::SignedAt),
+    ("signed_on", Msg::SignedOn),
+    ("signature", Msg::Signature),
+    ("keep_part", Msg::KeepPart),
+    ("cut_here", Msg::CutHere),
+    ("return_slip", Msg::ReturnSlip),
+    ("consent", Msg::Consent),
+    ("no_consent", Msg::NoConsent),
+];
+
+/// Gabarit utilisateur (`--template`), vérifié à la lecture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    /// Nom affiché dans les erreurs (chemin du fichier).
+    pub name: String,
+    pub source: String,
+}
+
+impl Template {
+    pub fn new(name: impl Into<String>, source: impl Into<String>) -> Result<Self> {
+        let template = Template {
+            name: name.into(),
+            source: source.into(),
+        };
+        environment(Locale::default())
+            .template_from_named_str(&template.name, &template.source)
+            .map_err(|e| anyhow::anyhow!("gabarit {}: {e:#}", template.name))?;
+        Ok(template)
+    }
+
+    /// Lit un gabarit ; une erreur de syntaxe est signalée avec sa ligne.
+    pub fn load(path: &Path) -> Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("lecture du gabarit {}", path.display()))?;
+        Self::new(path.display().to_string(), source)
+    }
+}
+
+/// Rend `source` pour `form` ; `name` nomme le gabarit dans les erreurs.
+pub fn render_template(
+    name: &str,
+    source: &str,
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+) -> Result<String> {
+    let loc = opts.locale(form);
+    environment(loc)
+        .render_named_str(name, source, context(form, opts))
+        .map_err(|e| anyhow::anyhow!("gabarit {name}: {e:#}"))
+}
+
+/// Rend le gabarit `--template` du renderer `id`, ou `default`.
+pub(crate) fn render_with_default(
+    id: &str,
+    default: &str,
+    form: &AutorisationForm,
+    opts: &RenderOptions,
+) -> Result<String> {
+    match opts.templates.get(id) {
+        Some(t) => render_template(&t.name, &t.source, form, opts),
+        None => render_template("<défaut>", default, form, opts),
+    }
+}
+
+fn environment(loc: Locale) -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.set_undefined_behavior(UndefinedBehavior::SemiStrict);
+    env.add_filter("date", move |value: &str, format: Option<&str>| {
+        format_date(value, format, loc)
+    });
+    env.add_filter("heure", move |value: &str| -> Result<String, Error> {
+        let time = crate::domain::form::parse_heure(value)
+            .map_err(|e| Error::new(ErrorKind::InvalidOperation, e.to_string()))?;
+        Ok(format_time(time, loc))
+    });
+    env.add_filter("colon", move |text: &str, lang: Option<&str>| {
+        let loc = match lang {
+            Some(code) => code.parse().map_err(|e: anyhow::Error| {
+                Error::new(ErrorKind::InvalidOperation, e.to_string())
+            })?,
+            None => loc,
+        };
+        Ok::<_, Error>(with_colon(text, loc))
+    });
+    env.add_filter("cell", |text: &str| text.replace('|', "\\|"));
+    env
+}
+
+/// Filtre `date` : `long` (par défaut), `short` (JJ/MM/AAAA) ou description `time`.
+fn format_date(value: &str, format: Option<&str>, loc: Locale) -> Result<String, Error> {
+    let invalid = |msg: String| Error::new(ErrorKind::InvalidOperation, msg);
+    let date = crate::domain::form::parse_date(value).map_err(|e| invalid(e.to_string()))?;
+    match format.unwrap_or("long") {
+        "long" => Ok(format_date_long(date, loc)),
+        "short" => Ok(value.to_string()),
+        description => {
+            let items = time::format_description::parse(description)
+                .map_err(|e| invalid(format!("format de date '{description}': {e}")))?;
+            date.format(&items).map_err(|e| invalid(e.to_string()))
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Field {
+    key: &'static str,
+    label: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct Child {
+    nom: String,
+    prenom: Option<String>,
+    classe: Option<String>,
+    nom_complet: String,
+}
+
+#[derive(Serialize)]
+struct Guardian {
+    nom: String,
+    lien: Option<&'static str>,
+    telephone: Option<String>,
+    email: Option<String>,
+    adresse: Option<String>,
+    signataire: bool,
+}
+
+#[derive(Serialize)]
+struct Contact {
+    nom: String,
+    lien: Option<String>,
+    telephone: String,
+}
+
+#[derive(Serialize)]
+struct Times {
+    debut: Option<String>,
+    fin: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SchoolContext {
+    name: String,
+    logo: Option<String>,
+    lines: Vec<String>,
+}
+
+/// Libellés, lignes et signatures dans une langue.
+#[derive(Serialize)]
+struct Translation {
+    lang: &'static str,
+    lang_name: &'static str,
+    t: BTreeMap<&'static str, &'static str>,
+    fields: Vec<Field>,
+    signatures: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Second {
+    #[serde(flatten)]
+    translation: Translation,
+    layout: &'static str,
+}
+
+#[derive(Serialize)]
+struct Context {
+    enfants: Vec<Child>,
+    date: String,
+    lieu: String,
+    responsables: Vec<Guardian>,
+    contacts_urgence: Vec<Contact>,
+    plage_horaire: Option<Times>,
+    horaires: Option<String>,
+    motif: Option<String>,
+
+    #[serde(flatten)]
+    primary: Translation,
+    rtl: bool,
+    layout: &'static str,
+    coupon: bool,
+    profile: Option<String>,
+    school: Option<SchoolContext>,
+    coupon_fields: Vec<Field>,
+    consents: [&'static str; 2],
+    second: Option<Second>,
+}
+
+fn key(msg: Msg) -> &'static str {
+    LABELS
+        .iter()
+        .find(|(_, m)| *m == msg)
+        .map(|(k, _)| *k)
+        .unwrap_or("other")
+}
+
+fn field_list(lines: Vec<(Msg, String)>, loc: Locale) -> Vec<Field> {
+    lines
+        .into_iter()
+        .map(|(msg, value)| Field {
+            key: key(msg),
+            label: with_colon(tr(msg, loc), loc),
+            value,
+        })
+        .collect()
+}
+
+fn translation(form: &AutorisationForm, loc: Locale) -> Translation {
+    Translation {
+        lang: loc.code(),
+        lang_name: loc.native_name(),
+        t: LABELS.iter().map(|(k, msg)| (*k, tr(*msg, loc))).collect(),
+        fields: field_list(fields(form, loc), loc),
+        signatures: signatures(form, loc),
+    }
+}
+
+fn context(form: &AutorisationForm, opts: &RenderOptions) -> Context {
+    let loc = opts.locale(form);
+    let time = |t: Option<time::Time>| t.map(|t| format!("{:02}:{:02}", t.hour(), t.minute()));
+    Context {
+        enfants: form
+            .enfants
+            .iter()
+            .map(|e| Child {
+                nom: e.nom.to_string(),
+                prenom: e.prenom.as_ref().map(ToString::to_string),
+                classe: e.classe.clone(),
+                nom_complet: e.nom_complet(),
+            })
+            .collect(),
+        date: format!(
+            "{:02}/{:02}/{}",
+            form.date.day(),
+            u8::from(form.date.month()),
+            form.date.year()
+        ),
+        lieu: form.lieu.to_string(),
+        responsables: form
+            .responsables
+            .iter()
+            .map(|r| Guardian {
+                nom: r.nom.to_string(),
+                lien: r.lien.map(|l| lien(l, loc)),
+                telephone: r.telephone.as_ref().map(ToString::to_string),
+                email: r.email.clone(),
+                adresse: r.adresse.clone(),
+                signataire: r.signataire,
+            })
+            .collect(),
+        contacts_urgence: form
+            .contacts_urgence
+            .iter()
+            .map(|c| Contact {
+                nom: c.nom.to_string(),
+                lien: c.lien.clone(),
+                telephone: c.telephone.to_string(),
+            })
+            .collect(),
+        plage_horaire: form.plage_horaire.map(|p| Times {
+            debut: time(p.debut()),
+            fin: time(p.fin()),
+        }),
+        horaires: form
+            .plage_horaire
+            .as_ref()
+            .and_then(|p| format_plage(p, loc)),
+        motif: form.motif.clone(),
+
+        primary: translation(form, loc),
+        rtl: loc.is_rtl(),
+        layout: match opts.layout {
+            DocumentLayout::Full => "full",
+            DocumentLayout::Coupon => "coupon",
+        },
+        coupon: opts.layout == DocumentLayout::Coupon,
+        profile: opts.profile.clone(),
+        school: opts.letterhead().map(|school| SchoolContext {
+            name: school.name.clone(),
+            logo: school.logo.as_ref().map(|l| l.display().to_string()),
+            lines: letterhead_lines(school, loc),
+        }),
+        coupon_fields: field_list(coupon_fields(form, loc), loc),
+        consents: consents(loc),
+        second: opts.bilingual.map(|bi| Second {
+            translation: translation(form, bi.secondary),
+            layout: match bi.layout {
+                BilingualLayout::Stacked => "stacked",
+                BilingualLayout::Columns => "columns",
+            },
+        }),
+    }
+}
@@ -0,0 +1,27 @@
+use crate::domain::form::AutorisationForm;
+use crate::render::template::{render_with_default, DEFAULT_TEXT};
+use crate::render::{RenderContext, RenderOptions, Renderer};
+use anyhow::Result;
+use std::io::Write;
+
+/// Moteur `txt` du registre : texte brut, pour un courriel ou un ENT.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextRenderer;
+
+impl Renderer for TextRenderer {
+    fn id(&self) -> &'static str {
+        "txt"
+    }
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        w.write_all(render_text(ctx.form, ctx.options)?.as_bytes())?;
+        w.flush()?;
+        Ok(())
+    }
+}
+
+/// Texte brut de `form` : gabarit `--template txt=…` (`opts.templates`), sinon
+/// `template::DEFAULT_TEXT`.
+pub fn render_text(form: &AutorisationForm, opts: &RenderOptions) -> Result<String> {
+    render_with_default("txt", DEFAULT_TEXT, form, opts)
+}
@@ -0,0 +1,247 @@
+// src/render/theme.rs
+#![forbid(unsafe_code)]
+
+//! Thèmes typographiques : tailles, espacements, couleurs, police et alignement des titres,
+//! partagés par les moteurs PDF et le HTML. Le Markdown n'a pas de typographie et les ignore.
+//!
+//! Un thème se choisit par son nom (`compact`, `standard`, `large-print`) ou se lit dans un
+//! fichier TOML ou YAML ; les clés absentes du fichier gardent la valeur de `standard`.
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Couleur RGB, notée `#rrggbb` (ou `#rgb`) dans les fichiers de thème.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+
+    /// Composantes entre 0 et 1 (opérateurs de couleur PDF).
+    pub fn unit(self) -> [f32; 3] {
+        [self.r, self.g, self.b].map(|c| c as f32 / 255.0)
+    }
+}
+
+impl FromStr for Color {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.trim().trim_start_matches('#');
+        // chiffres ASCII seulement : le découpage par octets ne coupe pas de caractère
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            anyhow::bail!("couleur invalide: '{s}' (#rrggbb)");
+        }
+        let expanded: String = match hex.len() {
+            3 => hex.chars().flat_map(|c| [c, c]).collect(),
+            6 => hex.to_string(),
+            _ => anyhow::bail!("couleur invalide: '{s}' (#rrggbb)"),
+        };
+        let channel = |i: usize| {
+            u8::from_str_radix(&expanded[i..i + 2], 16)
+                .map_err(|_| anyhow::anyhow!("couleur invalide: '{s}' (#rrggbb)"))
+        };
+        Ok(Color {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        })
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Alignement horizontal des titres (nom de l'école, titre du document).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeadingAlign {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+impl HeadingAlign {
+    /// Valeur CSS / Typst.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            HeadingAlign::Left => "left",
+            HeadingAlign::Center => "center",
+            HeadingAlign::Right => "right",
+        }
+    }
+}
+
+/// Thème du document. Tailles en points ; `baseline_pt` est l'unité des espacements
+/// verticaux, `line_height` l'interligne en multiple de la taille du texte.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    pub name: String,
+    /// Famille de police (nom de fichier pour genpdf, nom de police pour Typst et le HTML) ;
+    /// à défaut, la police par défaut de chaque moteur.
+    pub font_family: Option<String>,
+    /// Titre du document.
+    pub h1_pt: f32,
+    /// Nom de l'école, enfant mis en avant.
+    pub h2_pt: f32,
+    /// Intertitres (motif, coupon-réponse).
+    pub h3_pt: f32,
+    pub body_pt: f32,
+    /// Mentions et traductions.
+    pub caption_pt: f32,
+    pub baseline_pt: f32,
+    pub line_height: f32,
+    pub text_color: Color,
+    pub heading_color: Color,
+    pub heading_align: HeadingAlign,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::standard()
+    }
+}
+
+impl Theme {
+    /// Noms des thèmes intégrés.
+    pub const BUILTIN: [&'static str; 3] = ["compact", "standard", "large-print"];
+
+    /// Corps 12 pt, titres 22 / 18 / 14 pt.
+    pub fn standard() -> Self {
+        Theme {
+            name: "standard".into(),
+            font_family: None,
+            h1_pt: 22.0,
+            h2_pt: 18.0,
+            h3_pt: 14.0,
+            body_pt: 12.0,
+            caption_pt: 9.0,
+            baseline_pt: 6.0,
+            line_height: 1.4,
+            text_color: Color::BLACK,
+            heading_color: Color::BLACK,
+            heading_align: HeadingAlign::Center,
+        }
+    }
+
+    /// Corps 10 pt, espacements resserrés : tient sur une demi-page (coupon, imposition A5).
+    pub fn compact() -> Self {
+        Theme {
+            name: "compact".into(),
+            h1_pt: 16.0,
+            h2_pt: 13.0,
+            h3_pt: 11.0,
+            body_pt: 10.0,
+            caption_pt: 8.0,
+            baseline_pt: 4.0,
+            line_height: 1.25,
+            ..Theme::standard()
+        }
+    }
+
+    /// Corps 16 pt pour les familles malvoyantes, titres alignés à gauche.
+    pub fn large_print() -> Self {
+        Theme {
+            name: "large-print".into(),
+            h1_pt: 32.0,
+            h2_pt: 24.0,
+            h3_pt: 18.0,
+            body_pt: 16.0,
+            caption_pt: 12.0,
+            baseline_pt: 8.0,
+            line_height: 1.5,
+            heading_align: HeadingAlign::Left,
+            ..Theme::standard()
+        }
+    }
+
+    /// Thème intégré `name`.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "standard" => Some(Theme::standard()),
+            "compact" => Some(Theme::compact()),
+            "large-print" | "large_print" | "gros-caracteres" => Some(Theme::large_print()),
+            _ => None,
+        }
+    }
+
+    /// Lit un fichier de thème TOML (`.toml`) ou YAML (`.yaml`, `.yml`).
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("lecture du thème {}", path.display()))?;
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        let mut theme: Theme = match ext.as_deref() {
+            Some("toml") => toml::from_str(&content).map_err(anyhow::Error::from),
+            Some("yaml" | "yml") => serde_yaml::from_str(&content).map_err(anyhow::Error::from),
+            _ => anyhow::bail!(
+                "thème {}: extension inconnue (.toml, .yaml, .yml)",
+                path.display()
+            ),
+        }
+        .with_context(|| format!("thème {}", path.display()))?;
+        if theme.name == Theme::standard().name {
+            theme.name = path
+                .file_stem()
+                .map_or_else(String::new, |s| s.to_string_lossy().into_owned());
+        }
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// Thème intégré si `spec` en est un nom, sinon fichier de thème.
+    pub fn resolve(spec: &str) -> Result<Self> {
+        match Theme::builtin(spec) {
+            Some(theme) => Ok(theme),
+            None if Path::new(spec).is_file() => Theme::load(Path::new(spec)),
+            None => anyhow::bail!(
+                "thème inconnu: '{spec}' ({}, ou chemin d'un fichier .toml/.yaml)",
+                Theme::BUILTIN.join("|")
+            ),
+        }
+    }
+
+    /// Refuse les tailles hors de 4 à 96 pt et un interligne hors de 1 à 3.
+    pub fn validate(&self) -> Result<()> {
+        for (key, size) in [
+            ("h1_pt", self.h1_pt),
+            ("h2_pt", self.h2_pt),
+            ("h3_pt", self.h3_pt),
+            ("body_pt", self.body_pt),
+            ("caption_pt", self.caption_pt),
+        ] {
+            if !(4.0..=96.0).contains(&size) {
+                anyhow::bail!("thème {}: {key} = {size} hors de 4 à 96 pt", self.name);
+            }
+        }
+        if !(0.0..=48.0).contains(&self.baseline_pt) {
+            anyhow::bail!("thème {}: baseline_pt hors de 0 à 48 pt", self.name);
+        }
+        if !(1.0..=3.0).contains(&self.line_height) {
+            anyhow::bail!("thème {}: line_height hors de 1 à 3", self.name);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+// src/render/typst.rs
+#![forbid(unsafe_code)]
+
+//! Rendu Typst : balisage `.typ` généré depuis le formulaire, compilé en PDF par le binaire
+//! `typst` local (https://typst.org). Typst gère le shaping et le sens d'écriture (arabe).
+use crate::domain::form::AutorisationForm;
+use crate::domain::school::School;
+use crate::i18n::catalog::{label, tr, Msg};
+use crate::i18n::Locale;
+use crate::infra::fs::write_atomic;
+use crate::render::logo::LOGO_HEIGHT_MM;
+use crate::render::{
+    consents, coupon_fields, fields, letterhead_lines, signatures, BilingualLayout, DocumentLayout,
+    RenderContext, RenderOptions, Renderer, Theme,
+};
+use anyhow::{Context, Result};
+use std::env;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Hauteur ajoutée à la marge haute pour un en-tête répété sur chaque page, en mm.
+const HEADER_MM: f64 = 28.0;
+
+/// Chaîne Typst entre guillemets (`"..."`), pour un argument de fonction.
+fn quoted(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+    format!("\"{escaped}\"")
+}
+
+/// Littéral de chaîne Typst, inséré tel quel en mode balisage via `#"..."`.
+fn lit(s: &str) -> String {
+    format!("#{}", quoted(s))
+}
+
+fn signed_at_on(loc: Locale) -> String {
+    format!(
+        "{} #box(width: 35%, repeat[.]), {} #box(width: 25%, repeat[.])",
+        lit(tr(Msg::SignedAt, loc)),
+        lit(tr(Msg::SignedOn, loc))
+    )
+}
+
+/// Titre aligné et coloré selon le thème ; `content` est déjà échappé.
+fn heading(t: &Theme, size_pt: f32, content: &str) -> String {
+    format!(
+        "#align({}, text(size: {size_pt}pt, weight: \"bold\", fill: rgb(\"{}\"))[{content}])",
+        t.heading_align.keyword(),
+        t.heading_color
+    )
+}
+
+/// En-tête de l'école (bloc de contenu) : logo à gauche s'il y en a un, nom et coordonnées.
+fn letterhead(t: &Theme, school: &School, loc: Locale) -> String {
+    let mut text = format!(
+        "#text(size: {}pt, weight: \"bold\", fill: rgb(\"{}\"))[{}]",
+        t.h2_pt,
+        t.heading_color,
+        lit(&school.name)
+    );
+    for line in letterhead_lines(school, loc) {
+        text.push_str(&format!(
+            " \\ #text(size: {}pt)[{}]",
+            t.caption_pt,
+            lit(&line)
+        ));
+    }
+    match school.logo.as_deref().and_then(|l| l.file_name()) {
+        // relatif à la racine du projet Typst, le répertoire du logo (voir `logo_root`)
+        Some(name) => format!(
+            "#grid(columns: (auto, 1fr), column-gutter: 1em, align: horizon, image({}, height: {LOGO_HEIGHT_MM}mm), [{text}])",
+            quoted(&format!("/{}", name.to_string_lossy()))
+        ),
+        None => format!("#align({})[{text}]", t.heading_align.keyword()),
+    }
+}
+
+/// Racine du projet Typst (`--root`) : le répertoire du logo de l'en-tête, qui y est lu par
+/// `/<nom du fichier>` ; `None` sans logo (racine par défaut de Typst).
+pub fn logo_root(opts: &RenderOptions) -> Option<PathBuf> {
+    let logo = opts.letterhead()?.logo.as_ref()?;
+    let logo = std::fs::canonicalize(logo).unwrap_or_else(|_| logo.clone());
+    logo.parent().map(Path::to_path_buf)
+}
+
+/// Balisage Typst complet du document (page, en-tête, champs, signatures).
+pub fn render_typst(form: &AutorisationForm, opts: &RenderOptions) -> Result<String> {
+    let loc = opts.locale(form);
+    let coupon = opts.layout == DocumentLayout::Coupon;
+    let (width, height) = opts.page.size_mm();
+    let m = opts.page.margins;
+    let school = opts.letterhead();
+    // en-tête répété : dans la marge haute, agrandie d'autant
+    let (top, header) = match school.filter(|s| s.every_page) {
+        Some(sc) => (
+            m.top + HEADER_MM,
+            format!(
+                ", header: [{}], header-ascent: 4mm",
+                letterhead(&opts.theme, sc, loc)
+            ),
+        ),
+        None => (m.top, String::new()),
+    };
+    let mut s = String::new();
+    s.push_str(&format!(
+        "#set document(title: {}{})\n",
+        quoted(tr(Msg::Title, loc)),
+        opts.keywords()
+            .map(|k| format!(", keywords: ({},)", quoted(&k)))
+            .unwrap_or_default()
+    ));
+    s.push_str(&format!(
+        "#set page(width: {width}mm, height: {height}mm, margin: (left: {}mm, right: {}mm, top: {top}mm, bottom: {}mm){header})\n",
+        m.left, m.right, m.bottom
+    ));
+    let t = &opts.theme;
+    let font = t
+        .font_family
+        .as_deref()
+        .map_or(String::new(), |f| format!(", font: {}", quoted(f)));
+    // `leading` est l'espace entre deux lignes, hors hauteur du texte (~0.6 em)
+    s.push_str(&format!(
+        "#set text(lang: \"{}\", size: {}pt, fill: rgb(\"{}\"){font})\n#set par(leading: {:.2}em)\n\n",
+        loc.code(),
+        t.body_pt,
+        t.text_color,
+        (t.line_height - 0.6).max(0.1)
+    ));
+    if let Some(school) = school.filter(|s| !s.every_page) {
+        s.push_str(&format!("{}\n#v(1em)\n", letterhead(t, school, loc)));
+    }
+
+    let Some(bi) = opts.bilingual else {
+        s.push_str(&format!(
+            "{}\n",
+            heading(t, t.h1_pt, &lit(tr(Msg::Title, loc)))
+        ));
+        if coupon {
+            s.push_str(&format!(
+                "#align(center, emph[{}])\n",
+                lit(tr(Msg::KeepPart, loc))
+            ));
+        }
+        s.push_str("#v(1.5em)\n\n");
+        s.push_str("#grid(columns: (auto, 1fr), column-gutter: 1em, row-gutter: 0.8em,\n");
+        for (msg, value) in fields(form, loc) {
+            s.push_str(&format!(
+                "  strong[{}], [{}],\n",
+                lit(&label(msg, loc)),
+                lit(&value)
+            ));
+        }
+        s.push_str(")\n");
+        if coupon {
+            push_coupon(&mut s, form, loc, t);
+        } else {
+            s.push_str("\n#v(2em)\n");
+            push_signatures(&mut s, form, loc);
+        }
+        return Ok(s);
+    };
+
+    let second = bi.secondary;
+    // seconde langue : sa propre direction d'écriture (français dans un document arabe...)
+    let small = |x: &str| {
+        format!(
+            "text(lang: \"{}\", size: {}pt, style: \"italic\")[{}]",
+            second.code(),
+            t.caption_pt,
+            lit(x)
+        )
+    };
+    s.push_str(&format!(
+        "#align({})[#text(size: {}pt, weight: \"bold\", fill: rgb(\"{}\"))[{}] \\ #{}]\n#v(1.5em)\n\n",
+        t.heading_align.keyword(),
+        t.h1_pt,
+        t.heading_color,
+        lit(tr(Msg::Title, loc)),
+        small(tr(Msg::Title, second))
+    ));
+    let rows = fields(form, loc).into_iter().zip(fields(form, second));
+    let sigs = signatures(form, loc)
+        .into_iter()
+        .zip(signatures(form, second));
+    match bi.layout {
+        BilingualLayout::Columns => {
+            s.push_str("#grid(columns: (1fr, 1fr), column-gutter: 2em, row-gutter: 0.8em,\n");
+            s.push_str(&format!(
+                "  strong[{}], text(lang: \"{}\", weight: \"bold\")[{}],\n",
+                lit(loc.native_name()),
+                second.code(),
+                lit(second.native_name())
+            ));
+            for ((msg, value), (_, value2)) in rows {
+                s.push_str(&format!(
+                    "  [#strong[{}] {}], {},\n",
+                    lit(&label(msg, loc)),
+                    lit(&value),
+                    small(&format!("{} {value2}", label(msg, second)))
+                ));
+            }
+            if !coupon {
+                s.push_str(&format!(
+                    "  v(1.5em), v(1.5em),\n  [{}], text(lang: \"{}\")[{}],\n",
+                    signed_at_on(loc),
+                    second.code(),
+                    signed_at_on(second)
+                ));
+                for (sig, sig2) in sigs {
+                    s.push_str(&format!(
+                        "  [#v(1em){}#v(2.5em)#line(length: 90%)], {},\n",
+                        lit(&sig),
+                        small(&sig2)
+                    ));
+                }
+            }
+            s.push_str(")\n");
+        }
+        BilingualLayout::Stacked => {
+            for ((msg, value), (_, value2)) in rows {
+                s.push_str(&format!(
+                    "#strong[{}] {} \\\n#{}\n\n",
+                    lit(&label(msg, loc)),
+                    lit(&value),
+                    small(&format!("{} {value2}", label(msg, second)))
+                ));
+            }
+            if !coupon {
+                s.push_str(&format!(
+                    "#v(2em)\n{} \\\n#text(lang: \"{}\")[{}]\n",
+                    signed_at_on(loc),
+                    second.code(),
+                    signed_at_on(second)
+                ));
+                for (sig, sig2) in sigs {
+                    s.push_str(&format!(
+                        "\n#v(1.5em)\n{} \\\n#{}\n#v(2.5em)\n#align(right, line(length: 45%))\n",
+                        lit(&sig),
+                        small(&sig2)
+                    ));
+                }
+            }
+        }
+    }
+    if coupon {
+        push_coupon(&mut s, form, loc, t);
+    }
+    Ok(s)
+}
+
+fn push_signatures(s: &mut String, form: &AutorisationForm, loc: Locale) {
+    s.push_str(&format!("{}\n", signed_at_on(loc)));
+    for signature in signatures(form, loc) {
+        s.push_str(&format!(
+            "\n#v(1.5em)\n{}\n#v(2.5em)\n#align(right, line(length: 45%))\n",
+            lit(&signature)
+        ));
+    }
+}
+
+/// Coupon-réponse en bas de page, insécable : pointillé avec ciseaux, rappel, cases
+/// d'accord et signatures.
+fn push_coupon(s: &mut String, form: &AutorisationForm, loc: Locale, t: &Theme) {
+    s.push_str("\n#v(1fr)\n#block(breakable: false, width: 100%)[\n");
+    s.push_str(&format!(
+        "#grid(columns: (auto, 1fr), column-gutter: 0.5em, align: horizon, text(size: {h3}pt)[✂], line(length: 100%, stroke: (thickness: 0.6pt, dash: \"dashed\")))\n#align(center, text(size: {caption}pt, style: \"italic\")[{}])\n#v(0.8em)\n#text(size: {h3}pt, weight: \"bold\", fill: rgb(\"{heading}\"))[{}]\n#v(0.5em)\n",
+        lit(tr(Msg::CutHere, loc)),
+        lit(tr(Msg::ReturnSlip, loc)),
+        h3 = t.h3_pt,
+        caption = t.caption_pt,
+        heading = t.heading_color
+    ));
+    s.push_str("#grid(columns: (auto, 1fr), column-gutter: 1em, row-gutter: 0.6em,\n");
+    for (msg, value) in coupon_fields(form, loc) {
+        s.push_str(&format!(
+            "  strong[{}], [{}],\n",
+            lit(&label(msg, loc)),
+            lit(&value)
+        ));
+    }
+    s.push_str(")\n#v(0.8em)\n");
+    for consent in consents(loc) {
+        s.push_str(&format!(
+            "#box(width: 0.8em, height: 0.8em, stroke: 0.6pt) #h(0.4em) {} \\\n",
+            lit(consent)
+        ));
+    }
+    s.push_str("#v(1.2em)\n");
+    push_signatures(s, form, loc);
+    s.push_str("]\n");
+}
+
+/// Moteur `typ` du registre : la source Typst seule, sans compilation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TypstSourceRenderer;
+
+impl Renderer for TypstSourceRenderer {
+    fn id(&self) -> &'static str {
+        "typ"
+    }
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        w.write_all(render_typst(ctx.form, ctx.options)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Binaire `typst` : `AUT_TYPST` s'il est défini, sinon recherche dans le `PATH`.
+pub fn find_typst() -> Option<PathBuf> {
+    match env::var_os("AUT_TYPST") {
+        Some(p) => Some(PathBuf::from(p)).filter(|p| p.is_file()),
+        None => which::which("typst").ok(),
+    }
+}
+
+/// `typst compile - -` : source sur l'entrée standard, PDF lu sur la sortie standard, avec
+/// la sortie d'erreur de Typst en cas d'échec. `root` (voir `logo_root`) devient la racine du
+/// projet, seul répertoire lisible par le document.
+pub fn compile(typst: &Path, source: &str, root: Option<&Path>) -> Result<Vec<u8>> {
+    let mut command = Command::new(typst);
+    command.arg("compile");
+    if let Some(root) = root {
+        command.arg("--root").arg(root);
+    }
+    let mut child = command
+        .args(["--format", "pdf", "-", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("impossible de lancer '{}'", typst.display()))?;
+    child
+        .stdin
+        .take()
+        .context("entrée standard de typst")?
+        .write_all(source.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "typst compile a échoué ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(output.stdout)
+}
+
+/// Document PDF compilé par le `typst` local, en mémoire.
+pub fn render_pdf_bytes(form: &AutorisationForm, opts: &RenderOptions) -> Result<Vec<u8>> {
+    let typst = find_typst()
+        .context("binaire `typst` introuvable (installez-le ou définissez AUT_TYPST)")?;
+    compile(
+        &typst,
+        &render_typst(form, opts)?,
+        logo_root(opts).as_deref(),
+    )
+}
+
+/// Rendu PDF via Typst, écrit atomiquement dans `out` (la source s'obtient avec `--format typ`).
+pub fn render_pdf_typst(form: &AutorisationForm, opts: &RenderOptions, out: &Path) -> Result<()> {
+    let bytes = render_pdf_bytes(form, opts)?;
+    write_atomic(out, &bytes).with_context(|| format!("écriture de {}", out.display()))
+}
@@ -0,0 +1,40 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::i18n::Locale;
+use autorisation::render::builtin::render_pdf_bytes;
+use autorisation::render::RenderOptions;
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+#[test]
+fn builtin_pdf_uses_standard_fonts_and_winansi_text() {
+    let yaml = "enfants: [{nom: Lefèvre, prenom: Zoé}]\ndate: 25/09/2025\nlieu: Musée — sœurs\n";
+    let f: AutorisationForm = serde_yaml::from_str(yaml).unwrap();
+    let pdf = render_pdf_bytes(&f, &RenderOptions::default()).unwrap();
+    assert!(pdf.starts_with(b"%PDF-"));
+    assert!(contains(&pdf, b"/BaseFont /Helvetica-Bold"));
+    assert!(contains(&pdf, b"/WinAnsiEncoding"));
+    assert!(!contains(&pdf, b"/FontFile"), "no embedded font");
+    // "Lefèvre Zoé" et "Musée — sœurs" en cp1252 (chaînes hexadécimales)
+    assert!(contains(&pdf, b"<4C6566E8767265205A6FE9>"));
+    assert!(contains(&pdf, b"<4D7573E965209720739C757273>"));
+}
+
+#[test]
+fn builtin_pdf_breaks_pages_and_rejects_arabic() {
+    let mut yaml = String::from("date: 25/09/2025\nlieu: Parc\nenfants:\n");
+    for i in 0..60 {
+        yaml.push_str(&format!("  - {{nom: Enfant{i}, classe: CM1}}\n"));
+    }
+    let f: AutorisationForm = serde_yaml::from_str(&yaml).unwrap();
+    let pdf = render_pdf_bytes(&f, &RenderOptions::default()).unwrap();
+    assert!(contains(&pdf, b"/Count 2") || contains(&pdf, b"/Count 3"));
+
+    let opts = RenderOptions {
+        lang: Some(Locale::Ar),
+        ..Default::default()
+    };
+    let err = render_pdf_bytes(&f, &opts).unwrap_err();
+    assert!(err.to_string().contains("--backend typst"), "{err}");
+}
@@ -0,0 +1,39 @@
+//! Aides partagées par les tests du binaire.
+#![allow(dead_code)]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Variables d'environnement lues par la configuration, retirées pour isoler les tests.
+const CONFIG_VARS: &[&str] = &[
+    "AUT_PAGE_SIZE",
+    "AUT_ORIENTATION",
+    "AUT_MARGINS",
+    "AUT_THEME",
+    "AUT_LANG",
+    "AUT_OUT_DIR",
+    "AUT_SCHOOL_NAME",
+    "AUT_PROFILE",
+];
+
+/// Binaire lancé dans `dir`, le répertoire de configuration de l'utilisateur sous `home`.
+pub fn command(dir: &Path, home: &Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_autorisation"));
+    cmd.current_dir(dir)
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join(".config"));
+    for var in CONFIG_VARS {
+        cmd.env_remove(var);
+    }
+    cmd
+}
+
+/// Sortie standard d'une exécution réussie.
+pub fn ok(output: Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
@@ -0,0 +1,154 @@
+use autorisation::domain::form::FormDefaults;
+use std::path::Path;
+
+mod common;
+
+const FORM: &str = "enfants: [{nom: Dupont, prenom: Jean}]\ndate: 25/09/2099\n";
+
+/// Sortie standard d'une exécution réussie, avec les variables `env` en plus.
+fn run(dir: &Path, home: &Path, env: &[(&str, &str)], args: &[&str]) -> String {
+    common::ok(
+        common::command(dir, home)
+            .envs(env.iter().copied())
+            .args(args)
+            .output()
+            .unwrap(),
+    )
+}
+
+#[test]
+fn user_config_is_overridden_by_project_env_then_flags() {
+    let dir = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("f.yaml"), FORM).unwrap();
+    let (d, h) = (dir.path(), home.path());
+    run(
+        d,
+        h,
+        &[],
+        &["config", "set", "school.name", "École Utilisateur"],
+    );
+    run(d, h, &[], &["config", "set", "defaults.lieu", "Saint-Malo"]);
+    run(d, h, &[], &["config", "set", "out_dir", "sorties"]);
+
+    let md = |env: &[(&str, &str)], extra: &[&str]| {
+        let mut args = vec!["--input", "f.yaml", "--format", "md", "--out", "-"];
+        args.extend(extra);
+        run(d, h, env, &args)
+    };
+    let doc = md(&[], &[]);
+    assert!(doc.starts_with("# École Utilisateur\n"), "{doc}");
+    assert!(doc.contains("**Lieu :** Saint-Malo"), "{doc}");
+
+    run(
+        d,
+        h,
+        &[],
+        &["config", "set", "--project", "school.name", "École Projet"],
+    );
+    assert!(md(&[], &[]).starts_with("# École Projet\n"));
+    let env = [("AUT_SCHOOL_NAME", "École Env")];
+    assert!(md(&env, &[]).starts_with("# École Env\n"));
+    assert!(md(&env, &["--school-name", "École CLI"]).starts_with("# École CLI\n"));
+
+    // out_dir du fichier utilisateur, relatif à celui-ci, quand --out est absent
+    run(d, h, &[], &["--input", "f.yaml", "--format", "md"]);
+    let shown = run(d, h, &[], &["config", "show"]);
+    assert!(shown.contains("name = \"École Projet\""), "{shown}");
+    let out = shown
+        .lines()
+        .find_map(|l| l.strip_prefix("out_dir = \""))
+        .unwrap()
+        .trim_end_matches('"')
+        .to_string();
+    assert!(Path::new(&out).join("autorisation_sortie.md").is_file());
+}
+
+#[test]
+fn config_set_rejects_unknown_keys_and_invalid_values() {
+    let dir = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    for (key, value) in [("page.paper", "a9"), ("colour", "red")] {
+        let output = common::command(dir.path(), home.path())
+            .args(["config", "set", "--project", key, value])
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("valeur refusée"));
+    }
+    assert!(!dir.path().join("autorisation.toml").exists());
+}
+
+#[test]
+fn defaults_fill_only_missing_fields() {
+    let defaults: FormDefaults = toml::from_str(
+        "lieu = \"Saint-Malo\"\nsignataire = { nom = \"Mme Martin\", lien = \"mere\" }\n",
+    )
+    .unwrap();
+    let mut value: serde_json::Value = serde_yaml::from_str(FORM).unwrap();
+    defaults.apply(&mut value);
+    assert_eq!(value["lieu"], "Saint-Malo");
+    assert_eq!(value["responsables"][0]["nom"], "Mme Martin");
+
+    let mut value: serde_json::Value = serde_yaml::from_str(&format!(
+        "{FORM}lieu: Musée\nresponsables: [{{nom: M. Dupont}}]\n"
+    ))
+    .unwrap();
+    defaults.apply(&mut value);
+    assert_eq!(value["lieu"], "Musée");
+    assert_eq!(value["responsables"][0]["nom"], "M. Dupont");
+
+    // ancienne clé singulière : pas de `responsables` ajouté à côté
+    let mut value: serde_json::Value =
+        serde_yaml::from_str(&format!("{FORM}responsable: {{nom: M. Dupont}}\n")).unwrap();
+    defaults.apply(&mut value);
+    assert!(value.get("responsables").is_none());
+    let (form, report) = autorisation::domain::validation::validate_document(value);
+    assert!(!report.has_errors(), "{report:?}");
+    assert_eq!(form.unwrap().responsables[0].nom.to_string(), "M. Dupont");
+}
+
+#[test]
+fn validate_input_applies_defaults_then_checks_classes() {
+    use autorisation::domain::parse::InputFormat;
+    use autorisation::domain::validation::validate_input;
+
+    let defaults: FormDefaults = toml::from_str("lieu = \"Saint-Malo\"\n").unwrap();
+    let source = FORM.replace("prenom: Jean", "prenom: Jean, classe: 6B");
+    let classes = vec!["6A".to_string()];
+    let (form, report) = validate_input(&source, InputFormat::Yaml, &defaults, &classes).unwrap();
+    assert_eq!(form.unwrap().lieu.to_string(), "Saint-Malo");
+    assert!(!report.has_errors(), "{report:?}");
+    assert!(
+        report.violations.iter().any(|v| v.message.contains("6B")),
+        "{report:?}"
+    );
+}
+
+#[test]
+fn config_set_keeps_comments_and_layout() {
+    use autorisation::infra::config::{create_profile, set_value, Config};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("autorisation.toml");
+    std::fs::write(
+        &path,
+        "# réglages de l'école\nlang = \"fr\"\ntheme = \"compact\" # demi-pages\n\n\
+         [page]\n# A5 pour la photocopieuse\npaper = \"a5\"\n",
+    )
+    .unwrap();
+    set_value(&path, "theme", "large-print").unwrap();
+    set_value(&path, "school.name", "École Jean Jaurès").unwrap();
+    let profile = Config {
+        classes: vec!["CP".into()],
+        ..Config::default()
+    };
+    create_profile(&path, "jaures", &profile).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "# réglages de l'école\nlang = \"fr\"\ntheme = \"large-print\" # demi-pages\n\n\
+         [page]\n# A5 pour la photocopieuse\npaper = \"a5\"\n\n\
+         [school]\nname = \"École Jean Jaurès\"\n\n\
+         [profiles.jaures]\nclasses = [\"CP\"]\n"
+    );
+}
@@ -0,0 +1,65 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::render::builtin::render_pdf_bytes;
+use autorisation::render::html::render_html;
+use autorisation::render::markdown::render_markdown;
+use autorisation::render::typst::render_typst;
+use autorisation::render::{DocumentLayout, RenderOptions};
+
+const FORM: &str = r#"
+enfants: [{nom: Dupont, prenom: Jean, classe: CM1}]
+date: 25/09/2025
+lieu: Musée
+motif: Visite
+responsables:
+  - {nom: Mme Dupont, lien: mere, signataire: true}
+"#;
+
+fn coupon() -> RenderOptions {
+    RenderOptions {
+        layout: "coupon".parse().unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn coupon_moves_signatures_below_the_cut_line() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    assert_eq!(coupon().layout, DocumentLayout::Coupon);
+    assert!("tear-off".parse::<DocumentLayout>().is_err());
+
+    let md = render_markdown(&f, &coupon()).unwrap();
+    let (info, slip) = md.split_once("✂").unwrap();
+    assert!(info.contains("_Partie à conserver par la famille_"));
+    assert!(info.contains("**Lieu :** Musée") && !info.contains("Signature"));
+    assert!(slip.contains("Coupon à découper et à rapporter"));
+    assert!(slip.contains(
+        "**Enfant :** Dupont Jean\n\n**Date :** jeudi 25 septembre 2025\n\n**Classe :** CM1"
+    ));
+    assert!(!slip.contains("Musée"));
+    assert!(slip.contains("- [ ] J'autorise mon enfant à participer à cette sortie."));
+    assert!(slip.contains("- [ ] Je n'autorise pas mon enfant"));
+    assert!(slip.contains("Signature du responsable légal — Mme Dupont (mère) :"));
+
+    let html = render_html(&f, &coupon()).unwrap();
+    let slip = &html[html.find("<section class=\"coupon\"").unwrap()..];
+    assert!(slip.contains("<h2>Coupon-réponse</h2>"));
+    assert!(slip.contains("<li>J&#39;autorise mon enfant à participer à cette sortie.</li>"));
+    assert_eq!(html.matches("class=\"signature\"").count(), 1);
+
+    let typ = render_typst(&f, &coupon()).unwrap();
+    assert!(typ.contains("#block(breakable: false, width: 100%)["));
+    assert!(typ.contains("dash: \"dashed\""));
+    assert_eq!(typ.matches("line(length: 45%)").count(), 1);
+}
+
+#[test]
+fn builtin_coupon_draws_scissors_and_dashed_cut() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let full = render_pdf_bytes(&f, &RenderOptions::default()).unwrap();
+    let pdf = render_pdf_bytes(&f, &coupon()).unwrap();
+    let text = String::from_utf8_lossy(&pdf);
+    assert!(text.contains("/BaseFont /ZapfDingbats"));
+    assert!(text.contains("[3 2] 0 d"), "dash pattern");
+    assert!(text.contains("/F4 12 Tf"));
+    assert!(!String::from_utf8_lossy(&full).contains("[3 2] 0 d"));
+}
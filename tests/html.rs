@@ -0,0 +1,46 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::domain::school::School;
+use autorisation::i18n::Locale;
+use autorisation::render::html::render_html;
+use autorisation::render::{Bilingual, BilingualLayout, RenderOptions};
+
+const FORM: &str = r#"
+enfants: [{nom: Dupont, prenom: Jean, classe: CM1}]
+date: 25/09/2025
+lieu: '<script>alert("x")</script> & Cie'
+responsables:
+  - {nom: Mme Dupont, lien: mere, signataire: true}
+"#;
+
+#[test]
+fn html_is_escaped_and_labelled() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        school: Some(School::named("École \"Jaurès\"")),
+        ..Default::default()
+    };
+    let html = render_html(&f, &opts).unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>\n<html lang=\"fr\" dir=\"ltr\">"));
+    assert!(!html.contains("<script>"), "{html}");
+    assert!(html.contains(
+        "<dt>Lieu :</dt><dd>&lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt; &amp; Cie</dd>"
+    ));
+    assert!(html.contains("<p class=\"school\">École &quot;Jaurès&quot;</p>"));
+    assert!(html.contains("@page { size: 210mm 297mm;"));
+    assert!(html.contains("<p>Signature du responsable légal — Mme Dupont (mère) :</p>"));
+}
+
+#[test]
+fn bilingual_html_marks_translation_language() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        bilingual: Some(Bilingual {
+            secondary: Locale::Ar,
+            layout: BilingualLayout::Columns,
+        }),
+        ..Default::default()
+    };
+    let html = render_html(&f, &opts).unwrap();
+    assert!(html.contains("<body class=\"columns\">"));
+    assert!(html.contains("<dt class=\"tr\" lang=\"ar\" dir=\"rtl\">المكان:</dt>"));
+}
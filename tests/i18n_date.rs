@@ -0,0 +1,30 @@
+use autorisation::i18n::date::{format_date_long, human_date};
+use autorisation::i18n::Locale;
+use time::macros::date;
+
+#[test]
+fn french_long_dates() {
+    assert_eq!(
+        format_date_long(date!(2025 - 09 - 25), Locale::Fr),
+        "jeudi 25 septembre 2025"
+    );
+    assert_eq!(
+        format_date_long(date!(2025 - 10 - 01), Locale::Fr),
+        "mercredi 1er octobre 2025"
+    );
+    assert_eq!(
+        human_date("15/08/2025", Locale::Fr),
+        "vendredi 15 août 2025"
+    );
+}
+
+#[test]
+fn locale_parameter_and_invalid_input() {
+    assert_eq!(
+        format_date_long(date!(2025 - 10 - 01), Locale::En),
+        "Wednesday 1 October 2025"
+    );
+    assert_eq!("fr-BE".parse::<Locale>().unwrap(), Locale::Fr);
+    // invalid dates are shown verbatim instead of panicking
+    assert_eq!(human_date("31/02/2025", Locale::Fr), "31/02/2025");
+}
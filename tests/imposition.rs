@@ -0,0 +1,85 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::render::{
+    render_pdf_imposed, render_pdf_to_vec, Imposition, PdfBackend, RenderOptions,
+};
+use std::process::Command;
+
+const FORM: &str = r#"
+enfants: [{nom: Dupont, prenom: Jean, classe: CM1}]
+date: 25/09/2099
+lieu: Musée
+responsables:
+  - {nom: Mme Dupont, lien: mere, signataire: true}
+"#;
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+fn count(haystack: &[u8], needle: &[u8]) -> usize {
+    haystack
+        .windows(needle.len())
+        .filter(|w| *w == needle)
+        .count()
+}
+
+fn two_up() -> RenderOptions {
+    RenderOptions {
+        imposition: "two-up".parse().unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn single_form_is_printed_twice_on_a_landscape_sheet() {
+    assert_eq!("2up".parse::<Imposition>().unwrap(), Imposition::TwoUp);
+    assert!("4up".parse::<Imposition>().is_err());
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let pdf = render_pdf_to_vec(PdfBackend::Auto, &f, &two_up()).unwrap();
+    assert!(contains(&pdf, b"/Count 1"));
+    assert!(contains(&pdf, b"/MediaBox [0 0 841.88"));
+    assert_eq!(count(&pdf, b"/Subtype /Form"), 2);
+    assert!(contains(&pdf, b"/S1 Do") && contains(&pdf, b"/S2 Do"));
+
+    let err = render_pdf_to_vec(PdfBackend::Typst, &f, &two_up()).unwrap_err();
+    assert!(err.to_string().contains("--backend builtin"), "{err}");
+}
+
+#[test]
+fn list_of_forms_fills_sheets_two_by_two() {
+    let forms: Vec<AutorisationForm> = ["Jean", "Zoé", "Léa"]
+        .iter()
+        .map(|p| serde_yaml::from_str(&FORM.replace("Jean", p)).unwrap())
+        .collect();
+    let pdf = render_pdf_imposed(PdfBackend::Builtin, &forms, &two_up()).unwrap();
+    assert!(contains(&pdf, b"/Count 2"));
+    assert_eq!(count(&pdf, b"/Subtype /Form"), 3);
+}
+
+#[test]
+fn cli_imposes_several_inputs_into_one_pdf() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut args = vec!["--imposition".to_string(), "two-up".to_string()];
+    for name in ["jean", "zoe"] {
+        let path = dir.path().join(format!("{name}.yaml"));
+        std::fs::write(&path, FORM.replace("Jean", name)).unwrap();
+        args.extend(["--input".to_string(), path.display().to_string()]);
+    }
+    let out = dir.path().join("planche.pdf");
+    let run = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_autorisation"))
+            .args(&args)
+            .args(["--out", out.to_str().unwrap()])
+            .args(extra)
+            .output()
+            .unwrap()
+    };
+    let output = run(&[]);
+    assert!(output.status.success(), "{output:?}");
+    let pdf = std::fs::read(&out).unwrap();
+    assert!(contains(&pdf, b"/Count 1"));
+    assert_eq!(count(&pdf, b"/Subtype /Form"), 2);
+
+    let output = run(&["--format", "md"]);
+    assert!(!output.status.success());
+}
@@ -1,26 +1,45 @@
 use autorisation::domain::form::AutorisationForm;
 use autorisation::render::pdf;
-use std::path::PathBuf;
+use time::macros::date;
 
 #[test]
 fn generate_pdf_integration() {
     let f = AutorisationForm {
-        enfant: autorisation::domain::form::Enfant {
-            nom: "Izi".into(),
-            prenom: Some("Test".into()),
-        },
-        date: "25/09/2025".into(),
-        lieu: "Chez moi".into(),
-        classe: None,
-        responsable: None,
+        enfants: vec![autorisation::domain::form::Enfant {
+            nom: "Izi".parse().unwrap(),
+            prenom: Some("Test".parse().unwrap()),
+            classe: None,
+        }],
+        date: date!(2025 - 09 - 25),
+        lieu: "Chez moi".parse().unwrap(),
+        responsables: vec![],
+        contacts_urgence: vec![],
         plage_horaire: None,
         motif: None,
+        langue: None,
     };
-    let mut out = PathBuf::from(std::env::temp_dir());
+    let mut out = std::env::temp_dir();
     out.push("autorisation_ci_test.pdf");
     let _ = std::fs::remove_file(&out);
-    let r = pdf::render_pdf(&f, None, &out);
+    let r = pdf::render_pdf(&f, &Default::default(), &out);
     assert!(r.is_ok());
     let md = std::fs::metadata(&out).expect("pdf produced");
     assert!(md.len() > 0);
 }
+
+#[test]
+fn genpdf_records_profile_keyword() {
+    let f: AutorisationForm =
+        serde_yaml::from_str("enfants: [{nom: Dupont}]\ndate: 25/09/2099\nlieu: Musée\n").unwrap();
+    let opts = autorisation::render::RenderOptions {
+        profile: Some("ecole-jaures".into()),
+        ..Default::default()
+    };
+    let bytes = pdf::render_pdf_bytes(&f, &opts).unwrap();
+    let doc = lopdf::Document::load_mem(&bytes).unwrap();
+    let info = doc.trailer.get(b"Info").unwrap().as_reference().unwrap();
+    let info = doc.get_dictionary(info).unwrap();
+    let text = |key: &[u8]| info.get(key).unwrap().as_str().unwrap().to_vec();
+    assert_eq!(text(b"Title"), b"Autorisation de sortie");
+    assert_eq!(text(b"Keywords"), b"profil:ecole-jaures");
+}
@@ -0,0 +1,108 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::domain::school::School;
+use autorisation::render::builtin::render_pdf_bytes;
+use autorisation::render::html::render_html;
+use autorisation::render::markdown::render_markdown;
+use autorisation::render::RenderOptions;
+use std::path::Path;
+
+const FORM: &str = "enfants: [{nom: Dupont, prenom: Jean}]\ndate: 25/09/2099\nlieu: Musée\n";
+
+const PROFILE: &str = "name = \"École Jean Jaurès\"
+academy = \"Académie de Rennes\"
+address = \"12 rue des Écoles\\n35400 Saint-Malo\"
+phone = \"02 99 12 34 56\"
+email = \"ecole@example.fr\"
+logo = \"logo.png\"
+";
+
+/// Profil d'école et logo PNG semi-transparent dans `dir`.
+fn school(dir: &Path) -> School {
+    let logo = image::RgbaImage::from_fn(8, 4, |x, _| image::Rgba([20, 60, 160, x as u8 * 32]));
+    logo.save(dir.join("logo.png")).unwrap();
+    std::fs::write(dir.join("ecole.toml"), PROFILE).unwrap();
+    School::load(&dir.join("ecole.toml")).unwrap()
+}
+
+#[test]
+fn profile_resolves_logo_and_rejects_other_formats() {
+    let dir = tempfile::tempdir().unwrap();
+    let school = school(dir.path());
+    assert_eq!(
+        school.logo.as_deref(),
+        Some(dir.path().join("logo.png").as_path())
+    );
+    assert!(!school.every_page);
+
+    let gif = dir.path().join("gif.yaml");
+    std::fs::write(&gif, "name: École\nlogo: logo.gif\n").unwrap();
+    let err = School::load(&gif).unwrap_err();
+    assert!(format!("{err:#}").contains("PNG ou JPEG"), "{err:#}");
+}
+
+#[test]
+fn html_and_markdown_show_logo_address_and_contacts() {
+    let dir = tempfile::tempdir().unwrap();
+    let form: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        school: Some(school(dir.path())),
+        ..RenderOptions::default()
+    };
+    let logo = dir.path().join("logo.png").display().to_string();
+
+    let md = render_markdown(&form, &opts).unwrap();
+    assert!(md.starts_with(&format!("![Logo]({logo})\n\n# École Jean Jaurès\n\n")));
+    assert!(md.contains(
+        "Académie de Rennes  \n12 rue des Écoles  \n35400 Saint-Malo  \nTél : 02 99 12 34 56 · Courriel : ecole@example.fr\n"
+    ), "{md}");
+
+    let html = render_html(&form, &opts).unwrap();
+    assert!(html.contains(&format!("<img class=\"logo\" src=\"{logo}\"")));
+    assert!(html.contains("<p class=\"details\">35400 Saint-Malo</p>"));
+    assert!(!html.contains("every-page"));
+}
+
+#[test]
+fn builtin_pdf_embeds_logo_with_soft_mask() {
+    let dir = tempfile::tempdir().unwrap();
+    let form: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        school: Some(school(dir.path())),
+        ..RenderOptions::default()
+    };
+    let pdf = String::from_utf8_lossy(&render_pdf_bytes(&form, &opts).unwrap()).into_owned();
+    assert!(pdf.contains("/Subtype /Image"));
+    assert!(pdf.contains("/SMask"));
+    assert!(pdf.contains("/Logo Do"));
+    assert!(pdf.contains("(35400 Saint-Malo)"));
+}
+
+#[cfg(unix)]
+#[test]
+fn typst_reads_the_logo_from_its_own_directory_only() {
+    use autorisation::render::typst::{compile, logo_root, render_typst};
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        school: Some(school(dir.path())),
+        ..Default::default()
+    };
+    let source = render_typst(&f, &opts).unwrap();
+    assert!(source.contains("image(\"/logo.png\""), "{source}");
+    let root = logo_root(&opts).unwrap();
+    assert_eq!(root, dir.path().canonicalize().unwrap());
+    assert_eq!(logo_root(&RenderOptions::default()), None);
+
+    // faux `typst` : affiche ses arguments à la place du PDF
+    let fake = dir.path().join("typst");
+    std::fs::write(&fake, "#!/bin/sh\ncat >/dev/null\nprintf '%s\\n' \"$@\"\n").unwrap();
+    std::fs::set_permissions(&fake, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let args = |root: Option<&Path>| String::from_utf8(compile(&fake, &source, root).unwrap());
+    assert_eq!(
+        args(Some(&root)).unwrap(),
+        format!("compile\n--root\n{}\n--format\npdf\n-\n-\n", root.display())
+    );
+    assert_eq!(args(None).unwrap(), "compile\n--format\npdf\n-\n-\n");
+}
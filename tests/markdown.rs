@@ -0,0 +1,150 @@
+use autorisation::domain::form::{AutorisationForm, Enfant, PlageHoraire};
+use autorisation::i18n::Locale;
+use autorisation::render::markdown::render_markdown;
+use autorisation::render::{Bilingual, BilingualLayout, RenderOptions};
+use time::macros::{date, time};
+
+#[test]
+fn markdown_shows_plage_horaire() {
+    let f = AutorisationForm {
+        enfants: vec![Enfant {
+            nom: "Dupont".parse().unwrap(),
+            prenom: Some("Jean".parse().unwrap()),
+            classe: None,
+        }],
+        date: date!(2025 - 09 - 25),
+        lieu: "Saint-Malo".parse().unwrap(),
+        responsables: vec![],
+        contacts_urgence: vec![],
+        plage_horaire: Some(PlageHoraire::new(Some(time!(14:00)), Some(time!(16:30))).unwrap()),
+        motif: None,
+        langue: None,
+    };
+    let md = render_markdown(&f, &RenderOptions::default()).unwrap();
+    assert!(md.contains("**Horaires :** de 14h00 à 16h30"));
+}
+
+#[test]
+fn markdown_translated_labels() {
+    let mut f = AutorisationForm {
+        enfants: vec![Enfant {
+            nom: "Dupont".parse().unwrap(),
+            prenom: None,
+            classe: Some("CM1".into()),
+        }],
+        date: date!(2025 - 09 - 25),
+        lieu: "Saint-Malo".parse().unwrap(),
+        responsables: vec![],
+        contacts_urgence: vec![],
+        plage_horaire: None,
+        motif: Some("Musée".into()),
+        langue: Some(Locale::De),
+    };
+    let md = render_markdown(&f, &RenderOptions::default()).unwrap();
+    assert!(md.contains("## Ausgangsgenehmigung"));
+    assert!(md.contains("**Datum:** Donnerstag, 25. September 2025"));
+
+    // --lang wins over the per-form `langue`
+    let opts = RenderOptions {
+        lang: Some(Locale::En),
+        ..Default::default()
+    };
+    let md = render_markdown(&f, &opts).unwrap();
+    assert!(md.contains("**Reason:** Musée"));
+    assert!(md.contains("Signature of legal guardian:"));
+
+    f.langue = Some(Locale::Es);
+    let md = render_markdown(&f, &RenderOptions::default()).unwrap();
+    assert!(md.contains("**Fecha:** jueves, 25 de septiembre de 2025"));
+}
+
+#[test]
+fn markdown_bilingual_layouts() {
+    let f = AutorisationForm {
+        enfants: vec![Enfant {
+            nom: "Dupont".parse().unwrap(),
+            prenom: Some("Jean".parse().unwrap()),
+            classe: None,
+        }],
+        date: date!(2025 - 09 - 25),
+        lieu: "Saint-Malo".parse().unwrap(),
+        responsables: vec![],
+        contacts_urgence: vec![],
+        plage_horaire: None,
+        motif: None,
+        langue: None,
+    };
+    let mut opts = RenderOptions {
+        bilingual: Some(Bilingual {
+            secondary: Locale::En,
+            layout: BilingualLayout::Columns,
+        }),
+        ..Default::default()
+    };
+    let md = render_markdown(&f, &opts).unwrap();
+    assert!(md.contains("## Autorisation de sortie / Permission to leave school"));
+    assert!(md.contains("| Français | English |"));
+    assert!(md
+        .contains("| **Date :** jeudi 25 septembre 2025 | **Date:** Thursday 25 September 2025 |"));
+
+    opts.bilingual = Some(Bilingual {
+        secondary: Locale::Es,
+        layout: BilingualLayout::Stacked,
+    });
+    let md = render_markdown(&f, &opts).unwrap();
+    assert!(md.contains("**Lieu :** Saint-Malo  \n_Lugar: Saint-Malo_"));
+    assert!(md.contains("_Firma del tutor legal:_"));
+}
+
+#[test]
+fn markdown_lists_every_sibling() {
+    let f = AutorisationForm {
+        enfants: vec![
+            Enfant {
+                nom: "Dupont".parse().unwrap(),
+                prenom: Some("Jean".parse().unwrap()),
+                classe: Some("CM1".into()),
+            },
+            Enfant {
+                nom: "Dupont".parse().unwrap(),
+                prenom: Some("Léa".parse().unwrap()),
+                classe: Some("CP".into()),
+            },
+        ],
+        date: date!(2025 - 09 - 25),
+        lieu: "Saint-Malo".parse().unwrap(),
+        responsables: vec![],
+        contacts_urgence: vec![],
+        plage_horaire: None,
+        motif: None,
+        langue: None,
+    };
+    let md = render_markdown(&f, &RenderOptions::default()).unwrap();
+    assert!(md.contains("**Enfant :** Dupont Jean (CM1)"));
+    assert!(md.contains("**Enfant :** Dupont Léa (CP)"));
+    assert!(!md.contains("**Classe :**"));
+    assert_eq!(md.matches("Signature du responsable légal").count(), 1);
+}
+
+#[test]
+fn markdown_one_signature_per_signing_guardian() {
+    let yaml = r#"
+enfants: [{nom: Dupont, prenom: Jean}]
+date: 25/09/2025
+lieu: Rennes
+responsables:
+  - {nom: Mme Dupont, lien: mere, telephone: "+33612345678"}
+  - {nom: M. Dupont, lien: pere}
+  - {nom: Mme Durand, lien: autre, signataire: false}
+contacts_urgence:
+  - {nom: M. Petit, lien: voisin, telephone: "02 99 00 00 00"}
+"#;
+    let f: AutorisationForm = serde_yaml::from_str(yaml).unwrap();
+    let md = render_markdown(&f, &RenderOptions::default()).unwrap();
+    assert!(md.contains("**Responsable légal :** M. Dupont (père)"));
+    assert!(md.contains("**Tél :** 06 12 34 56 78"));
+    assert!(md.contains("**Contact d'urgence :** M. Petit (voisin) — 02 99 00 00 00"));
+    assert!(md.contains("Signature du responsable légal — Mme Dupont (mère) : ____"));
+    assert!(md.contains("Signature du responsable légal — M. Dupont (père) : ____"));
+    assert_eq!(md.matches("Signature du responsable légal").count(), 2);
+}
@@ -0,0 +1,73 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::domain::school::School;
+use autorisation::render::docx::render_docx;
+use autorisation::render::odt::render_odt;
+use autorisation::render::RenderOptions;
+use std::io::{Cursor, Read};
+use zip::{CompressionMethod, ZipArchive};
+
+const FORM: &str = r#"
+enfants: [{nom: Dupont, prenom: Jean, classe: CM1}]
+date: 25/09/2025
+lieu: 'Musée <Art> & Cie'
+responsables:
+  - {nom: Mme Dupont, lien: mere, signataire: true}
+"#;
+
+fn entry(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> String {
+    let mut s = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut s)
+        .unwrap();
+    s
+}
+
+fn options() -> RenderOptions {
+    RenderOptions {
+        school: Some(School::named("École Jaurès")),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn odt_package_has_stored_mimetype_and_named_styles() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let mut odt = ZipArchive::new(Cursor::new(render_odt(&f, &options()).unwrap())).unwrap();
+    let first = odt.by_index(0).unwrap();
+    assert_eq!(first.name(), "mimetype");
+    assert_eq!(first.compression(), CompressionMethod::Stored);
+    drop(first);
+    assert_eq!(
+        entry(&mut odt, "mimetype"),
+        "application/vnd.oasis.opendocument.text"
+    );
+
+    let styles = entry(&mut odt, "styles.xml");
+    assert!(styles.contains("style:name=\"AutTitre\" style:display-name=\"Autorisation - Titre\""));
+    assert!(styles.contains("fo:page-width=\"210mm\""));
+    let content = entry(&mut odt, "content.xml");
+    assert!(content.contains("<text:p text:style-name=\"AutEcole\">École Jaurès</text:p>"));
+    assert!(content.contains(
+        "<text:h text:outline-level=\"1\" text:style-name=\"AutTitre\">Autorisation de sortie</text:h>"
+    ));
+    assert!(content.contains("</text:span> Musée &lt;Art&gt; &amp; Cie</text:p>"));
+    assert!(content.contains("<text:p text:style-name=\"AutSignature\">Signature du responsable légal — Mme Dupont (mère) :</text:p>"));
+}
+
+#[test]
+fn docx_package_links_styles_and_paragraphs() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let mut docx = ZipArchive::new(Cursor::new(render_docx(&f, &options()).unwrap())).unwrap();
+    assert!(entry(&mut docx, "[Content_Types].xml").contains("/word/styles.xml"));
+    assert!(entry(&mut docx, "word/_rels/document.xml.rels").contains("Target=\"styles.xml\""));
+
+    let styles = entry(&mut docx, "word/styles.xml");
+    assert!(styles.contains("w:styleId=\"AutTitre\"><w:name w:val=\"Autorisation - Titre\"/>"));
+    assert!(styles.contains("<w:outlineLvl w:val=\"0\"/>"));
+    let document = entry(&mut docx, "word/document.xml");
+    assert!(document.contains("<w:pStyle w:val=\"AutSignature\"/>"));
+    assert!(document.contains(">Musée &lt;Art&gt; &amp; Cie</w:t>"));
+    assert!(document.contains("<w:pgSz w:w=\"11906\" w:h=\"16838\" w:orient=\"portrait\"/>"));
+}
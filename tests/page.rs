@@ -0,0 +1,111 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::render::html::render_html;
+use autorisation::render::typst::render_typst;
+use autorisation::render::{Margins, Orientation, PageConfig, PageSetup, PaperSize, RenderOptions};
+use std::process::Command;
+
+const FORM: &str = "enfants: [{nom: Dupont, prenom: Jean}]\ndate: 25/09/2099\nlieu: Musée\n";
+
+#[test]
+fn page_settings_parse_and_combine_by_priority() {
+    assert_eq!(
+        "Legal".parse::<PaperSize>().unwrap().size_mm(),
+        (215.9, 355.6)
+    );
+    assert_eq!(
+        "100x150mm".parse::<PaperSize>().unwrap(),
+        PaperSize::Custom {
+            width_mm: 100.0,
+            height_mm: 150.0
+        }
+    );
+    assert!("b4".parse::<PaperSize>().is_err());
+    assert!("10x20".parse::<PaperSize>().is_err());
+    let m: Margins = "10, 20mm".parse().unwrap();
+    assert_eq!((m.top, m.right, m.bottom, m.left), (10.0, 20.0, 10.0, 20.0));
+    let m: Margins = "5,6,7,8".parse().unwrap();
+    assert_eq!((m.top, m.right, m.bottom, m.left), (5.0, 6.0, 7.0, 8.0));
+    assert!("1,2,3,4,5".parse::<Margins>().is_err());
+
+    let cli = PageConfig {
+        paper: Some(PaperSize::A5),
+        ..Default::default()
+    };
+    let file: PageConfig =
+        toml::from_str("paper = \"legal\"\norientation = \"landscape\"\nmargins = \"12\"").unwrap();
+    let page = cli.or(PageConfig::default()).or(file).resolve().unwrap();
+    assert_eq!(page.paper, PaperSize::A5);
+    assert_eq!(page.orientation, Orientation::Landscape);
+    assert_eq!(page.size_mm(), (210.0, 148.0));
+    assert_eq!(page.margins.left, 12.0);
+
+    let cramped = PageConfig {
+        paper: Some(PaperSize::A5),
+        margins: Some("60".parse().unwrap()),
+        ..Default::default()
+    };
+    assert!(cramped.resolve().is_err());
+}
+
+#[test]
+fn renderers_use_per_side_margins() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        page: PageSetup {
+            paper: PaperSize::A5,
+            orientation: Orientation::Landscape,
+            margins: "10,15,20,25".parse().unwrap(),
+        },
+        ..Default::default()
+    };
+    let typ = render_typst(&f, &opts).unwrap();
+    assert!(typ.contains(
+        "#set page(width: 210mm, height: 148mm, margin: (left: 25mm, right: 15mm, top: 10mm, bottom: 20mm))"
+    ));
+    let html = render_html(&f, &opts).unwrap();
+    assert!(html.contains("@page { size: 210mm 148mm; margin: 10mm 15mm 20mm 25mm; }"));
+}
+
+#[test]
+fn cli_reads_config_file_then_env_then_flags() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("f.yaml"), FORM).unwrap();
+    std::fs::write(
+        dir.path().join("autorisation.toml"),
+        "[page]\npaper = \"a5\"\norientation = \"landscape\"\n",
+    )
+    .unwrap();
+    let run = |env: &[(&str, &str)], extra: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_autorisation"))
+            .current_dir(dir.path())
+            // pas de configuration de l'utilisateur
+            .env("XDG_CONFIG_HOME", dir.path())
+            .env_remove("AUT_PAGE_SIZE")
+            .env_remove("AUT_ORIENTATION")
+            .env_remove("AUT_MARGINS")
+            .envs(env.iter().copied())
+            .args(["--input", "f.yaml", "--backend", "builtin", "--out", "-"])
+            .args(extra)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let pdf = output.stdout;
+        let start = pdf.windows(10).position(|w| w == b"/MediaBox ").unwrap();
+        let end = start + pdf[start..].iter().position(|&b| b == b']').unwrap();
+        String::from_utf8_lossy(&pdf[start..=end]).into_owned()
+    };
+    // A5 paysage : 595.28 × 419.53 pt
+    let a5_landscape = run(&[], &[]);
+    assert!(
+        a5_landscape.starts_with("/MediaBox [0 0 595.2"),
+        "{a5_landscape}"
+    );
+    assert!(a5_landscape.contains(" 419.5"), "{a5_landscape}");
+    let letter = run(&[("AUT_PAGE_SIZE", "letter")], &[]);
+    assert!(letter.starts_with("/MediaBox [0 0 792 612"), "{letter}");
+    let a4 = run(
+        &[("AUT_PAGE_SIZE", "letter")],
+        &["--paper", "a4", "--orientation", "portrait"],
+    );
+    assert!(a4.contains(" 841.8"), "{a4}");
+}
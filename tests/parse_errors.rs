@@ -0,0 +1,51 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::domain::parse::{closest, deserialize, InputFormat};
+
+#[test]
+fn typed_parse_error_has_position_snippet_and_suggestion() {
+    let yaml = "enfants:\n  - nom: Dupont\n    prenon: Léa\ndate: 25/09/2030\nlieu: Parc\n";
+    let err = deserialize::<AutorisationForm>(yaml, InputFormat::Yaml).unwrap_err();
+    assert_eq!(err.line, Some(3));
+    assert!(
+        err.message.contains("vouliez-vous dire `prenom`"),
+        "{}",
+        err.message
+    );
+    let shown = err.to_string();
+    assert!(shown.starts_with("yaml invalide (ligne 3"), "{shown}");
+    assert!(shown.contains("3 |     prenon: Léa"), "{shown}");
+
+    let json = "{\"enfants\": [{\"nom\": \"A\"}],\n \"date\": \"25/09/2030\",\n \"lieu\": }";
+    let err = deserialize::<AutorisationForm>(json, InputFormat::Json).unwrap_err();
+    assert_eq!(
+        (err.format, err.line, err.column),
+        (InputFormat::Json, Some(3), Some(10))
+    );
+
+    assert_eq!(
+        closest("telephonne", &["nom", "telephone"]),
+        Some("telephone")
+    );
+    assert_eq!(closest("xyz", &["nom", "telephone"]), None);
+}
+
+#[test]
+fn format_comes_from_extension_or_override() {
+    let dir = tempfile::tempdir().unwrap();
+    let txt = dir.path().join("form.txt");
+    std::fs::write(&txt, "enfants: [{nom: X}]\ndate: 25/09/2030\nlieu: Parc\n").unwrap();
+    let path = txt.to_str().unwrap();
+
+    let err = AutorisationForm::from_file(path, None).unwrap_err();
+    assert!(err.to_string().contains("--input-format"), "{err}");
+    assert!(AutorisationForm::from_file(path, Some(InputFormat::Yaml)).is_ok());
+    // plus de repli silencieux : du YAML lu comme JSON échoue avec une erreur JSON
+    let err = AutorisationForm::from_file(path, Some(InputFormat::Json)).unwrap_err();
+    assert!(
+        err.to_string().starts_with("json invalide (ligne 1"),
+        "{err}"
+    );
+
+    assert_eq!(InputFormat::from_path("a.YAML"), Some(InputFormat::Yaml));
+    assert_eq!("yml".parse::<InputFormat>().unwrap(), InputFormat::Yaml);
+}
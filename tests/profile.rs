@@ -0,0 +1,163 @@
+use common::ok;
+use std::path::Path;
+use std::process::Output;
+
+mod common;
+
+const FORM: &str =
+    "enfants: [{nom: Dupont, prenom: Jean, classe: CM2}]\ndate: 25/09/2099\nlieu: Musée\n";
+
+fn run(dir: &Path, home: &Path, args: &[&str]) -> Output {
+    common::command(dir, home).args(args).output().unwrap()
+}
+
+#[test]
+fn profiles_are_created_listed_and_selected() {
+    let dir = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    let (d, h) = (dir.path(), home.path());
+    std::fs::write(d.join("f.yaml"), FORM).unwrap();
+    for (name, school) in [
+        ("ecole-jaures", "École Jaurès"),
+        ("ecole-ferry", "École Ferry"),
+    ] {
+        ok(run(
+            d,
+            h,
+            &[
+                "profile",
+                "create",
+                name,
+                "--school-name",
+                school,
+                "--classes",
+                "CP,CE1",
+            ],
+        ));
+    }
+    let output = run(
+        d,
+        h,
+        &["profile", "create", "ecole-ferry", "--school-name", "X"],
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("existe déjà"));
+    assert!(!run(d, h, &["profile", "use", "inconnu"]).status.success());
+
+    ok(run(d, h, &["profile", "use", "ecole-jaures"]));
+    let list = ok(run(d, h, &["profile", "list"]));
+    assert_eq!(
+        list,
+        "  ecole-ferry\tÉcole Ferry\n* ecole-jaures\tÉcole Jaurès\n"
+    );
+
+    let md = |extra: &[&str]| {
+        let mut args = vec!["--input", "f.yaml", "--format", "md", "--out", "-"];
+        args.extend(extra);
+        let output = run(d, h, &args);
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        (ok(output), stderr)
+    };
+    let (doc, stderr) = md(&[]);
+    assert!(doc.starts_with("# École Jaurès\n"), "{doc}");
+    assert!(stderr.contains("warning[unknown_class]"), "{stderr}");
+    assert!(md(&["--profile", "ecole-ferry"])
+        .0
+        .starts_with("# École Ferry\n"));
+    assert!(!run(d, h, &["--profile", "zz", "--input", "f.yaml"])
+        .status
+        .success());
+
+    let shown = ok(run(d, h, &["config", "show", "--profile", "ecole-ferry"]));
+    assert!(shown.contains("profile = \"ecole-ferry\""), "{shown}");
+    assert!(shown.contains("name = \"École Ferry\""), "{shown}");
+    assert!(!run(d, h, &["config", "show", "--profile", "zz"])
+        .status
+        .success());
+}
+
+#[test]
+fn active_profile_is_recorded_in_document_metadata() {
+    let dir = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    let (d, h) = (dir.path(), home.path());
+    std::fs::write(d.join("f.yaml"), FORM).unwrap();
+    std::fs::write(
+        d.join("autorisation.toml"),
+        "[profiles.ecole-jaures]\ntheme = \"compact\"\nschool = { name = \"École Jaurès\" }\n",
+    )
+    .unwrap();
+    ok(run(
+        d,
+        h,
+        &[
+            "--profile",
+            "ecole-jaures",
+            "--input",
+            "f.yaml",
+            "--format",
+            "pdf,html,docx",
+            "--backend",
+            "builtin",
+            "--out",
+            "doc",
+        ],
+    ));
+    let pdf = String::from_utf8_lossy(&std::fs::read(d.join("doc.pdf")).unwrap()).into_owned();
+    assert!(pdf.contains("/Keywords (profil:ecole-jaures)"));
+    let html = std::fs::read_to_string(d.join("doc.html")).unwrap();
+    assert!(html.contains("<meta name=\"keywords\" content=\"profil:ecole-jaures\">"));
+    let docx = std::fs::File::open(d.join("doc.docx")).unwrap();
+    let mut core = String::new();
+    std::io::Read::read_to_string(
+        &mut zip::ZipArchive::new(docx)
+            .unwrap()
+            .by_name("docProps/core.xml")
+            .unwrap(),
+        &mut core,
+    )
+    .unwrap();
+    assert!(core.contains("<cp:keywords>profil:ecole-jaures</cp:keywords>"));
+}
+
+#[test]
+fn nested_profiles_are_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("autorisation.toml");
+    std::fs::write(&path, "[profiles.a]\nprofile = \"b\"\n").unwrap();
+    let err = autorisation::infra::config::Config::load(&path).unwrap_err();
+    assert!(
+        format!("{err:#}").contains("pas de profil dans un profil"),
+        "{err:#}"
+    );
+}
+
+#[test]
+fn theme_files_resolve_from_any_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    let (d, h) = (dir.path(), home.path());
+    let proj = d.join("proj");
+    std::fs::create_dir(&proj).unwrap();
+    std::fs::write(d.join("f.yaml"), FORM).unwrap();
+    std::fs::write(proj.join("mine.toml"), "heading_color = \"#1f4e8c\"\n").unwrap();
+    std::fs::write(
+        proj.join("autorisation.toml"),
+        "[profiles.projet]\ntheme = \"mine.toml\"\n",
+    )
+    .unwrap();
+    ok(run(
+        &proj,
+        h,
+        &["profile", "create", "p1", "--theme", "mine.toml"],
+    ));
+
+    let html = |args: &[&str]| {
+        let mut all = vec!["--input", "f.yaml", "--format", "html", "--out", "-"];
+        all.extend(args);
+        ok(run(d, h, &all))
+    };
+    assert!(html(&["--profile", "p1"]).contains("#1f4e8c"));
+    let config = proj.join("autorisation.toml");
+    let config = config.to_str().unwrap();
+    assert!(html(&["--config", config, "--profile", "projet"]).contains("#1f4e8c"));
+}
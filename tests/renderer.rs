@@ -0,0 +1,145 @@
+use anyhow::Result;
+use autorisation::domain::form::AutorisationForm;
+use autorisation::render::markdown::{render_markdown, render_markdown_to_vec};
+use autorisation::render::{
+    render_pdf_to_vec, render_pdf_to_writer, PdfBackend, Registry, RenderContext, RenderOptions,
+    Renderer,
+};
+use std::io::{Cursor, Write};
+use std::process::{Command, Stdio};
+
+const FORM: &str = r#"
+enfants: [{nom: Dupont, prenom: Jean, classe: CM1}]
+date: 25/09/2025
+lieu: Musée
+responsables:
+  - {nom: Mme Dupont, lien: mere, telephone: "06 12 34 56 78"}
+"#;
+
+struct Csv;
+
+impl Renderer for Csv {
+    fn id(&self) -> &'static str {
+        "csv"
+    }
+
+    fn render_to_writer(&self, ctx: &RenderContext<'_>, w: &mut dyn Write) -> Result<()> {
+        writeln!(w, "lieu;{}", ctx.form.lieu)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn registry_lists_defaults_and_rejects_unknown_formats() {
+    let registry = Registry::with_defaults();
+    assert_eq!(
+        registry.ids(),
+        ["pdf", "md", "txt", "typ", "html", "odt", "docx"]
+    );
+    assert_eq!(registry.get("MD").unwrap().id(), "md");
+    let err = registry.require("rtf").err().unwrap().to_string();
+    assert!(
+        err.contains("'rtf'") && err.contains("pdf, md, txt, typ, html, odt, docx"),
+        "{err}"
+    );
+}
+
+#[test]
+fn custom_renderer_is_registered_and_used() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions::default();
+    let ctx = RenderContext::new(&f, &opts);
+    let dir = tempfile::tempdir().unwrap();
+
+    let mut registry = Registry::with_defaults();
+    registry.register(Csv);
+    assert_eq!(
+        registry.ids(),
+        ["pdf", "md", "txt", "typ", "html", "odt", "docx", "csv"]
+    );
+    let out = dir.path().join("a.csv");
+    registry.require("csv").unwrap().render(&ctx, &out).unwrap();
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "lieu;Musée\n");
+
+    let md = dir.path().join("a.md");
+    registry.require("md").unwrap().render(&ctx, &md).unwrap();
+    assert!(std::fs::read_to_string(&md).unwrap().contains("Jean"));
+}
+
+#[test]
+fn documents_render_in_memory_and_to_writers() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions::default();
+
+    let pdf = render_pdf_to_vec(PdfBackend::Builtin, &f, &opts).unwrap();
+    assert!(pdf.starts_with(b"%PDF-"));
+    let mut sink = Cursor::new(Vec::new());
+    let used = render_pdf_to_writer(PdfBackend::Builtin, &f, &opts, &mut sink).unwrap();
+    assert_eq!(used, PdfBackend::Builtin);
+    assert_eq!(sink.into_inner(), pdf);
+
+    let md = render_markdown_to_vec(&f, &opts).unwrap();
+    assert_eq!(md, render_markdown(&f, &opts).unwrap().into_bytes());
+    let ctx = RenderContext::new(&f, &opts);
+    let via_registry = Registry::with_defaults()
+        .require("md")
+        .unwrap()
+        .render_to_vec(&ctx)
+        .unwrap();
+    assert_eq!(via_registry, md);
+}
+
+#[test]
+fn cli_reads_stdin_and_writes_stdout() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_autorisation"))
+        .args(["--input", "-", "--out", "-", "--format", "md"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(FORM.replace("2025", "2099").as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let md = String::from_utf8(output.stdout).unwrap();
+    assert!(md.starts_with("## Autorisation de sortie"), "{md}");
+    assert!(md.contains("Dupont Jean"));
+}
+
+#[test]
+fn cli_requires_french_primary_for_bilingual() {
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_autorisation"))
+            .args([
+                "--input",
+                "examples/autorisation.yml",
+                "--out",
+                "-",
+                "--format",
+                "md",
+            ])
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    let same = run(&["--bilingual", "fr"]);
+    assert!(!same.status.success());
+    let stderr = String::from_utf8_lossy(&same.stderr);
+    assert!(stderr.contains("--bilingual fr"), "{stderr}");
+
+    // le texte français fait foi : pas de document bilingue sans français
+    let foreign = run(&["--lang", "en", "--bilingual", "de"]);
+    assert!(!foreign.status.success());
+    let stderr = String::from_utf8_lossy(&foreign.stderr);
+    assert!(
+        stderr.contains("document en français (langue en"),
+        "{stderr}"
+    );
+
+    assert!(run(&["--bilingual", "de"]).status.success());
+}
@@ -0,0 +1,138 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::i18n::Locale;
+use autorisation::render::markdown::render_markdown;
+use autorisation::render::template::render_template;
+use autorisation::render::{RenderOptions, Template};
+use std::process::Command;
+
+const FORM: &str = "enfants: [{nom: Dupont, prenom: Jean, classe: CM2}, {nom: Dupont, prenom: Léa}]
+date: 01/10/2099
+lieu: Musée
+plage_horaire: {debut: '14:00', fin: '16:30'}
+responsables: [{nom: Mme Dupont, lien: mere, telephone: '06 12 34 56 78'}]
+";
+
+const TEMPLATE: &str = "Sortie du {{ date | date }} ({{ date | date(\"[day].[month].[year]\") }})
+{% for e in enfants %}
+- {{ e.prenom }} {{ e.nom }}{% if e.classe %}, {{ e.classe }}{% endif %}
+
+{% endfor %}
+{% for r in responsables %}
+{{ t.guardian | colon }} {{ r.nom }} ({{ r.lien }}), {{ r.telephone }}
+{% endfor %}
+{% if plage_horaire %}
+{{ plage_horaire.debut | heure }} → {{ plage_horaire.fin | heure }}
+{% endif %}
+{% if motif %}
+{{ motif }}
+{% else %}
+Sans motif
+{% endif %}
+";
+
+#[test]
+fn template_has_variables_loops_conditionals_and_filters() {
+    let form: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let out = render_template("t", TEMPLATE, &form, &RenderOptions::default()).unwrap();
+    assert_eq!(
+        out,
+        "Sortie du jeudi 1er octobre 2099 (01.10.2099)
+- Jean Dupont, CM2
+- Léa Dupont
+Responsable légal : Mme Dupont (mère), 06 12 34 56 78
+14h00 → 16h30
+Sans motif
+"
+    );
+    let opts = RenderOptions {
+        lang: Some(Locale::En),
+        ..RenderOptions::default()
+    };
+    let out = render_template("t", TEMPLATE, &form, &opts).unwrap();
+    assert!(
+        out.starts_with("Sortie du Thursday 1 October 2099"),
+        "{out}"
+    );
+    assert!(out.contains("Legal guardian: Mme Dupont (mother)"), "{out}");
+}
+
+#[test]
+fn template_errors_name_the_template() {
+    let err = Template::new("ecole.md", "{% if lieu %}\n{{ lieu }}\n").unwrap_err();
+    assert!(err.to_string().contains("gabarit ecole.md"), "{err:#}");
+
+    let form: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        templates: [(
+            "md".into(),
+            Template::new("ecole.md", "{{ lieux }}").unwrap(),
+        )]
+        .into(),
+        ..RenderOptions::default()
+    };
+    let err = render_markdown(&form, &opts).unwrap_err();
+    assert!(format!("{err:#}").contains("undefined"), "{err:#}");
+}
+
+#[test]
+fn template_flag_selects_markdown_template() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("f.yaml"), FORM).unwrap();
+    std::fs::write(
+        dir.path().join("ecole.md"),
+        "# {{ t.title }} — {{ lieu }}\n{{ enfants | length }} enfant(s)\n",
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_autorisation"))
+        .current_dir(dir.path())
+        .env("XDG_CONFIG_HOME", dir.path())
+        .args(["--input", "f.yaml", "--format", "md", "--out", "-"])
+        .args(["--template", "ecole.md"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "# Autorisation de sortie — Musée\n2 enfant(s)\n"
+    );
+}
+
+#[test]
+fn template_flag_is_keyed_by_format() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("f.yaml"), FORM).unwrap();
+    std::fs::write(dir.path().join("ecole.md"), "# {{ lieu }}\n").unwrap();
+    std::fs::write(dir.path().join("ecole.txt"), "{{ lieu | upper }}\n").unwrap();
+    let run = |template: &str| {
+        Command::new(env!("CARGO_BIN_EXE_autorisation"))
+            .current_dir(dir.path())
+            .env("XDG_CONFIG_HOME", dir.path())
+            .args(["--input", "f.yaml", "--format", "md,txt", "--out", "sortie"])
+            .args(["--template", template])
+            .output()
+            .unwrap()
+    };
+
+    let ambiguous = run("ecole.md");
+    assert!(!ambiguous.status.success());
+    let stderr = String::from_utf8_lossy(&ambiguous.stderr);
+    assert!(
+        stderr.contains("précisez md=ecole.md ou txt=ecole.md"),
+        "{stderr}"
+    );
+    assert!(!dir.path().join("sortie.txt").exists());
+
+    let keyed = run("md=ecole.md,txt=ecole.txt");
+    assert!(
+        keyed.status.success(),
+        "{}",
+        String::from_utf8_lossy(&keyed.stderr)
+    );
+    let read = |name: &str| std::fs::read_to_string(dir.path().join(name)).unwrap();
+    assert_eq!(read("sortie.md"), "# Musée\n");
+    assert_eq!(read("sortie.txt"), "MUSÉE\n");
+}
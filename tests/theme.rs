@@ -0,0 +1,96 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::render::html::render_html;
+use autorisation::render::theme::{Color, HeadingAlign};
+use autorisation::render::typst::render_typst;
+use autorisation::render::{RenderOptions, Theme};
+
+const FORM: &str = "enfants: [{nom: Dupont, prenom: Jean}]\ndate: 25/09/2099\nlieu: Musée\n";
+
+#[test]
+fn builtin_themes_and_files_resolve() {
+    assert_eq!(Theme::default().body_pt, 12.0);
+    assert_eq!(Theme::resolve("compact").unwrap().body_pt, 10.0);
+    let large = Theme::resolve("Large-Print").unwrap();
+    assert_eq!(
+        (large.body_pt, large.heading_align),
+        (16.0, HeadingAlign::Left)
+    );
+    let err = Theme::resolve("baroque").unwrap_err();
+    assert!(
+        err.to_string().contains("compact|standard|large-print"),
+        "{err}"
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let toml = dir.path().join("ecole.toml");
+    std::fs::write(&toml, "body_pt = 11\nheading_color = \"#1f4e8c\"\n").unwrap();
+    let theme = Theme::resolve(toml.to_str().unwrap()).unwrap();
+    assert_eq!(theme.name, "ecole");
+    assert_eq!((theme.body_pt, theme.h1_pt), (11.0, 22.0));
+    assert_eq!(
+        theme.heading_color,
+        Color {
+            r: 0x1f,
+            g: 0x4e,
+            b: 0x8c
+        }
+    );
+
+    let yaml = dir.path().join("bad.yml");
+    std::fs::write(&yaml, "body_pt: 200\n").unwrap();
+    assert!(Theme::load(&yaml).is_err());
+    std::fs::write(&yaml, "body_size: 12\n").unwrap();
+    assert!(Theme::load(&yaml).is_err());
+    assert!("#12345".parse::<Color>().is_err());
+    for bad in ["#€", "#éé0", "#12345é"] {
+        assert!(bad.parse::<Color>().is_err(), "{bad}");
+    }
+    std::fs::write(&toml, "heading_color = \"#€\"\n").unwrap();
+    assert!(Theme::load(&toml).is_err());
+    assert_eq!("#f00".parse::<Color>().unwrap().to_string(), "#ff0000");
+}
+
+#[test]
+fn html_and_typst_follow_the_theme() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        theme: Theme {
+            font_family: Some("Luciole".into()),
+            heading_color: "#336699".parse().unwrap(),
+            ..Theme::large_print()
+        },
+        ..Default::default()
+    };
+    let html = render_html(&f, &opts).unwrap();
+    assert!(
+        html.contains("font-family: \"Luciole\", \"Helvetica Neue\""),
+        "{html}"
+    );
+    assert!(html.contains("font-size: 16pt; line-height: 1.5;"));
+    assert!(html.contains("h1 { text-align: left; font-size: 32pt; color: #336699;"));
+
+    let typ = render_typst(&f, &opts).unwrap();
+    assert!(typ.contains(
+        "#set text(lang: \"fr\", size: 16pt, fill: rgb(\"#000000\"), font: \"Luciole\")"
+    ));
+    assert!(typ.contains(
+        "#align(left, text(size: 32pt, weight: \"bold\", fill: rgb(\"#336699\"))[#\"Autorisation de sortie\"])"
+    ));
+}
+
+#[test]
+fn typst_escapes_font_family_strings() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let opts = RenderOptions {
+        theme: Theme {
+            font_family: Some("Luciole\\\")#pagebreak()\n".into()),
+            ..Theme::default()
+        },
+        ..Default::default()
+    };
+    let typ = render_typst(&f, &opts).unwrap();
+    assert!(
+        typ.contains(", font: \"Luciole\\\\\\\")#pagebreak()\\n\")"),
+        "{typ}"
+    );
+}
@@ -0,0 +1,53 @@
+use autorisation::domain::form::AutorisationForm;
+use autorisation::i18n::Locale;
+use autorisation::render::typst::render_typst;
+use autorisation::render::{
+    render_pdf_with, Bilingual, BilingualLayout, PdfBackend, RenderOptions,
+};
+
+const FORM: &str = r#"
+enfants: [{nom: Dupont, prenom: Jean, classe: CM1}]
+date: 25/09/2025
+lieu: 'Musée "Art #1" \ [atelier]'
+responsables:
+  - {nom: Mme Dupont, lien: mere, telephone: "06 12 34 56 78"}
+"#;
+
+#[test]
+fn typst_markup_escapes_values_and_follows_locale() {
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let typ = render_typst(&f, &RenderOptions::default()).unwrap();
+    assert!(typ.contains("#set text(lang: \"fr\""));
+    assert!(
+        typ.contains(r#"[#"Musée \"Art #1\" \\ [atelier]"]"#),
+        "{typ}"
+    );
+    assert!(typ.contains("#\"Signature du responsable légal — Mme Dupont (mère) :\""));
+
+    let opts = RenderOptions {
+        lang: Some(Locale::Ar),
+        bilingual: Some(Bilingual {
+            secondary: Locale::Fr,
+            layout: BilingualLayout::Columns,
+        }),
+        ..Default::default()
+    };
+    let typ = render_typst(&f, &opts).unwrap();
+    assert!(typ.contains("#set text(lang: \"ar\""));
+    assert!(typ.contains("#grid(columns: (1fr, 1fr)"));
+}
+
+#[test]
+fn missing_typst_falls_back_to_genpdf_in_auto_mode() {
+    std::env::set_var("AUT_TYPST", "/nonexistent/typst");
+    let f: AutorisationForm = serde_yaml::from_str(FORM).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("slip.pdf");
+    let opts = RenderOptions::default();
+
+    let err = render_pdf_with(PdfBackend::Typst, &f, &opts, &out).unwrap_err();
+    assert!(err.to_string().contains("typst"), "{err}");
+    let used = render_pdf_with(PdfBackend::Auto, &f, &opts, &out).unwrap();
+    assert_eq!(used, PdfBackend::Genpdf);
+    assert!(out.exists());
+}
@@ -0,0 +1,77 @@
+use autorisation::domain::parse::{parse_value, InputFormat};
+use autorisation::domain::validation::validate_source;
+use std::process::Command;
+
+const BAD_YAML: &str = "\
+enfants:
+  - nom: Dupont
+  - nom: Martin
+    classe: 12
+date: 25/09/2030
+lieu: Parc
+responsables:
+  - nom: Mme Martin
+    telephone: abc
+";
+
+#[test]
+fn diagnostics_point_to_source_positions() {
+    let (_, report) = validate_source(BAD_YAML, InputFormat::Yaml).unwrap();
+    let diags = report.diagnostics(BAD_YAML);
+    let at = |path: &str| {
+        let d = diags.iter().find(|d| d.violation.path == path).unwrap();
+        (d.line, d.column)
+    };
+    assert_eq!(at("enfants[1].classe"), (Some(4), Some(5)));
+    assert_eq!(at("responsables[0].telephone"), (Some(9), Some(5)));
+
+    let json = "{\n  \"enfants\": [{\"nom\": \"A\"}],\n  \"date\": \"x\",\n  \"lieu\": \"P\"\n}";
+    let (_, report) = validate_source(json, InputFormat::Json).unwrap();
+    let d = &report.diagnostics(json)[0];
+    assert_eq!(
+        (d.violation.code, d.line, d.column),
+        ("invalid_date", Some(3), Some(3))
+    );
+
+    let err = parse_value("{\n  \"date\": ,\n}", InputFormat::Json).unwrap_err();
+    assert_eq!(err.line, Some(2));
+}
+
+#[test]
+fn validate_subcommand_exit_codes() {
+    let dir = tempfile::tempdir().unwrap();
+    let bad = dir.path().join("bad.yml");
+    let broken = dir.path().join("broken.json");
+    std::fs::write(&bad, BAD_YAML).unwrap();
+    std::fs::write(&broken, "{\"enfants\": [").unwrap();
+    let run = |args: &[&std::path::Path]| {
+        Command::new(env!("CARGO_BIN_EXE_autorisation"))
+            .arg("validate")
+            .args(args)
+            .args(["--format", "json"])
+            .output()
+            .unwrap()
+    };
+
+    let examples: Vec<_> = std::fs::read_dir("examples")
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .collect();
+    let examples: Vec<&std::path::Path> = examples.iter().map(|p| p.as_path()).collect();
+    let ok = run(&examples);
+    assert_eq!(ok.status.code(), Some(0));
+    let out: serde_json::Value = serde_json::from_slice(&ok.stdout).unwrap();
+    for file in out.as_array().unwrap() {
+        assert_eq!(file["diagnostics"], serde_json::json!([]), "{file}");
+    }
+
+    let invalid = run(&[&bad]);
+    assert_eq!(invalid.status.code(), Some(1));
+    let out: serde_json::Value = serde_json::from_slice(&invalid.stdout).unwrap();
+    assert_eq!(out[0]["status"], "invalid");
+    assert_eq!(out[0]["diagnostics"][0]["line"], 4);
+
+    assert_eq!(run(&[&bad, &broken]).status.code(), Some(3));
+    let missing = dir.path().join("absent.yml");
+    assert_eq!(run(&[&broken, &missing]).status.code(), Some(4));
+}
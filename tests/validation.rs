@@ -1,35 +1,225 @@
-use autorisation::domain::form::AutorisationForm;
+use autorisation::domain::form::{AutorisationForm, PlageHoraire};
+use autorisation::domain::types::{parse_date, Name, PhoneNumber};
+use autorisation::domain::validation::{validate_document, Severity};
+use time::macros::{date, time};
 
 #[test]
 fn valid_form_parses_and_validates() {
     let f = AutorisationForm {
-        enfant: autorisation::domain::form::Enfant {
-            nom: "Dupont".into(),
-            prenom: Some("Jean".into()),
-        },
-        date: "25/09/2025".into(),
-        lieu: "Rennes".into(),
-        classe: Some("CM1".into()),
-        responsable: None,
+        enfants: vec![autorisation::domain::form::Enfant {
+            nom: "Dupont".parse().unwrap(),
+            prenom: Some("Jean".parse().unwrap()),
+            classe: Some("CM1".into()),
+        }],
+        date: date!(2025 - 09 - 25),
+        lieu: "Rennes".parse().unwrap(),
+        responsables: vec![],
+        contacts_urgence: vec![],
         plage_horaire: None,
         motif: None,
+        langue: None,
     };
     assert!(f.validate().is_ok());
 }
 
 #[test]
 fn invalid_date_fails() {
-    let f = AutorisationForm {
-        enfant: autorisation::domain::form::Enfant {
-            nom: "X".into(),
-            prenom: None,
-        },
-        date: "31/02/2025".into(),
-        lieu: "X".into(),
-        classe: None,
-        responsable: None,
-        plage_horaire: None,
-        motif: None,
-    };
-    assert!(f.validate().is_err());
+    let yaml = "enfants: [{nom: X}]\ndate: 31/02/2025\nlieu: X\n";
+    assert!(serde_yaml::from_str::<AutorisationForm>(yaml).is_err());
+
+    let message = |s: &str| parse_date(s).unwrap_err().to_string();
+    assert_eq!(message("25/13/2025"), "mois invalide 13 (1 à 12)");
+    assert_eq!(
+        message("31/02/2025"),
+        "jour invalide 31 (1 à 28 pour ce mois)"
+    );
+    assert_eq!(
+        message("29/02/2024x"),
+        "année invalide '2024x' (nombre attendu)"
+    );
+    assert_eq!(parse_date("29/02/2024").unwrap(), date!(2024 - 02 - 29));
+}
+
+#[test]
+fn plage_horaire_must_be_ordered() {
+    assert!(PlageHoraire::new(Some(time!(14:00)), Some(time!(16:30))).is_ok());
+    assert!(PlageHoraire::new(Some(time!(16:30)), Some(time!(14:00))).is_err());
+
+    let base = "enfants: [{nom: X}]\ndate: 25/09/2025\nlieu: X\n";
+    let ok = format!("{base}plage_horaire: {{debut: '14:00', fin: '16:30'}}\n");
+    let f: AutorisationForm = serde_yaml::from_str(&ok).unwrap();
+    assert_eq!(f.plage_horaire.unwrap().fin(), Some(time!(16:30)));
+    for bad in [
+        "plage_horaire: {debut: '16:30', fin: '14:00'}",
+        "plage_horaire: {debut: '25:00'}",
+    ] {
+        assert!(serde_yaml::from_str::<AutorisationForm>(&format!("{base}{bad}\n")).is_err());
+    }
+}
+
+#[test]
+fn typed_values_reject_invalid_input() {
+    assert!(Name::new("   ").is_err());
+    assert!(Name::new("x".repeat(81)).is_err());
+    assert_eq!(Name::new("  Dupont ").unwrap().as_str(), "Dupont");
+    assert!(PhoneNumber::parse("+++1").is_err());
+    assert!(PhoneNumber::parse("06 12 34 56 7a").is_err());
+    assert_eq!(
+        PhoneNumber::parse("06 12 34 56 78").unwrap().normalized(),
+        "+33612345678"
+    );
+
+    // round trip keeps the JJ/MM/AAAA and HH:MM wire formats
+    let yaml = "enfants: [{nom: X}]\ndate: 05/09/2025\nlieu: X\nplage_horaire: {debut: '09:05'}\n";
+    let f: AutorisationForm = serde_yaml::from_str(yaml).unwrap();
+    let json = serde_json::to_string(&f).unwrap();
+    assert!(json.contains(r#""date":"05/09/2025""#), "{json}");
+    assert!(
+        json.contains(r#""plage_horaire":{"debut":"09:05"}"#),
+        "{json}"
+    );
+}
+
+#[test]
+fn legacy_single_enfant_still_deserializes() {
+    let yaml =
+        "enfant:\n  nom: Dupont\n  prenom: Jean\ndate: 25/09/2025\nlieu: Rennes\nclasse: CM1\n";
+    let f: AutorisationForm = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(f.enfants.len(), 1);
+    assert_eq!(f.enfants[0].classe.as_deref(), Some("CM1"));
+
+    let json = r#"{"enfants": [{"nom": "Dupont", "classe": "CP"}, {"nom": "Dupont", "prenom": "Léa"}],
+        "date": "25/09/2025", "lieu": "Rennes", "classe": "CM2"}"#;
+    let f: AutorisationForm = serde_json::from_str(json).unwrap();
+    assert_eq!(f.enfants[0].classe.as_deref(), Some("CP"));
+    assert_eq!(f.enfants[1].classe.as_deref(), Some("CM2"));
+
+    let both = "enfant: {nom: A}\nenfants: [{nom: B}]\ndate: 25/09/2025\nlieu: Rennes\n";
+    assert!(serde_yaml::from_str::<AutorisationForm>(both).is_err());
+}
+
+#[test]
+fn every_child_is_validated() {
+    let json = r#"{"enfants": [{"nom": "Dupont"}, {"nom": "  "}],
+        "date": "25/09/2025", "lieu": "Rennes"}"#;
+    assert!(serde_json::from_str::<AutorisationForm>(json).is_err());
+
+    let json = r#"{"enfants": [{"nom": "Dupont"}, {"nom": "Dupont", "classe": "une classe au nom vraiment beaucoup trop long"}],
+        "date": "25/09/2025", "lieu": "Rennes"}"#;
+    let f: AutorisationForm = serde_json::from_str(json).unwrap();
+    let err = f.validate().unwrap_err().to_string();
+    assert!(err.contains("enfants[1]"), "{err}");
+}
+
+#[test]
+fn guardians_and_emergency_contacts() {
+    let yaml = r#"
+enfants: [{nom: Dupont}]
+date: 25/09/2025
+lieu: Rennes
+responsables:
+  - {nom: Mme Dupont, lien: mère, telephone: "06 12 34 56 78", email: a.dupont@example.fr}
+  - {nom: M. Martin, lien: pere, adresse: "3 rue des Lilas, 35000 Rennes"}
+contacts_urgence:
+  - {nom: Mme Durand, lien: grand-mère, telephone: "02 99 00 00 00"}
+"#;
+    let f: AutorisationForm = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(f.responsables.len(), 2);
+    assert!(f.responsables.iter().all(|r| r.signataire));
+    assert_eq!(
+        f.responsables[0].lien,
+        Some(autorisation::domain::form::Lien::Mere)
+    );
+    assert!(f.validate().is_ok());
+
+    let mut bad = f.clone();
+    bad.responsables[0].email = Some("pas-un-email".into());
+    assert!(bad.validate().is_err());
+
+    // legacy single `responsable` still accepted
+    let legacy = "enfant: {nom: A}\ndate: 25/09/2025\nlieu: Rennes\nresponsable: {nom: Mme A, telephone: '0612345678'}\n";
+    let f: AutorisationForm = serde_yaml::from_str(legacy).unwrap();
+    assert_eq!(f.responsables.len(), 1);
+}
+
+#[test]
+fn report_collects_every_violation_with_paths() {
+    let yaml = r#"
+enfants: [{nom: "", prenon: X}]
+date: 31/02/2025
+lieu: Rennes
+responsables: [{nom: A, telephone: "+++1", email: nope}]
+plage_horaire: {debut: "16:00", fin: "14:00"}
+"#;
+    let (form, report) = validate_document(serde_yaml::from_str(yaml).unwrap());
+    assert!(form.is_none());
+    let found: Vec<(&str, &str)> = report.errors().map(|v| (v.path.as_str(), v.code)).collect();
+    for expected in [
+        ("enfants[0].prenon", "unknown_field"),
+        ("enfants[0].nom", "invalid_name"),
+        ("date", "invalid_date"),
+        ("responsables[0].telephone", "invalid_phone"),
+        ("responsables[0].email", "invalid_email"),
+        ("plage_horaire.fin", "time_range_order"),
+    ] {
+        assert!(
+            found.contains(&expected),
+            "{expected:?} missing in {found:?}"
+        );
+    }
+}
+
+#[test]
+fn warnings_do_not_block() {
+    let yaml = "enfants: [{nom: Dupont}, {nom: Dupont}]\ndate: 01/01/2020\nlieu: Rennes\n";
+    let (form, report) = validate_document(serde_yaml::from_str(yaml).unwrap());
+    assert!(form.is_some());
+    assert!(!report.has_errors());
+    let codes: Vec<&str> = report.warnings().map(|v| v.code).collect();
+    assert_eq!(codes, ["duplicate_child", "date_in_past", "no_guardian"]);
+    assert!(report
+        .violations
+        .iter()
+        .all(|v| v.severity == Severity::Warning));
+}
+
+#[test]
+fn phone_numbers_follow_numbering_plans() {
+    let p = |s: &str| PhoneNumber::parse(s).unwrap();
+    // DOM : composés en 0 depuis la France, indicatif propre en E.164
+    assert_eq!(p("0692 12 34 56").normalized(), "+262692123456");
+    assert_eq!(p("0692 12 34 56").region(), Some("RE"));
+    assert_eq!(p("0596.12.34.56").region(), Some("MQ"));
+    assert_eq!(p("+33 (0)6 12 34 56 78").normalized(), "+33612345678");
+    assert_eq!(p("0032 470 12 34 56").region(), Some("BE"));
+    assert_eq!(p("+41 79 123 45 67").national(), "079 123 45 67");
+
+    // affichage : national en France, international pour l'étranger
+    assert_eq!(p("0612345678").to_string(), "06 12 34 56 78");
+    assert_eq!(p("+33612345678").to_string(), "06 12 34 56 78");
+    assert_eq!(p("+32470123456").to_string(), "+32 470 12 34 56");
+
+    for bad in [
+        "612345678",
+        "06 12 34",
+        "+32 12",
+        "+33 6 12 34 56 78 90",
+        "+0 1234 5678",
+    ] {
+        assert!(PhoneNumber::parse(bad).is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn check_document_knows_every_serialized_field() {
+    // Un champ ajouté à une structure ressort (au moins `null`) à la sérialisation : la liste
+    // des champs connus de `check_document` doit suivre.
+    let yaml = "enfants: [{nom: Dupont, prenom: Jean, classe: CM1}]\ndate: 25/09/2099\n\
+        lieu: Rennes\nresponsables: [{nom: Mme Dupont, lien: mere, telephone: '0612345678', \
+        email: a@b.fr, adresse: 1 rue X, signataire: true}]\n\
+        contacts_urgence: [{nom: M. Martin, lien: voisin, telephone: '0612345679'}]\n\
+        plage_horaire: {debut: '14:00', fin: '16:30'}\nmotif: Sortie\nlangue: fr\n";
+    let form: AutorisationForm = serde_yaml::from_str(yaml).unwrap();
+    let (_, report) = validate_document(serde_json::to_value(&form).unwrap());
+    assert!(report.violations.is_empty(), "{report:?}");
 }